@@ -0,0 +1,131 @@
+use {IppChannelLayout, IppImageResize, IppImageResizeKind, IppPixel, IppResizeSpec, IppResult};
+
+use std::any::{Any, TypeId};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct IppResizeKey {
+  kind:     (u8, u32, u32),
+  aa:       bool,
+  pixel:    TypeId,
+  layout:   IppChannelLayout,
+  src:      (usize, usize),
+  dst:      (usize, usize),
+}
+
+// `IppImageResizeKind` holds floats, so key on their bit patterns.
+fn kind_key(kind: IppImageResizeKind) -> (u8, u32, u32) {
+  match kind {
+    IppImageResizeKind::Nearest           => (0, 0, 0),
+    IppImageResizeKind::Linear            => (1, 0, 0),
+    IppImageResizeKind::Cubic{b, c}       => (2, b.to_bits(), c.to_bits()),
+    IppImageResizeKind::Lanczos{nlobes}   => (3, nlobes as u32, 0),
+    IppImageResizeKind::Super             => (4, 0, 0),
+  }
+}
+
+/// A bounded, least recently used cache of resize specs, keyed by the
+/// interpolation kind, pixel type, channel layout and src and dst sizes.
+/// Specs of any pixel type may share one cache.
+pub struct IppResizeCache {
+  cap:      usize,
+  tick:     u64,
+  specs:    HashMap<IppResizeKey, (u64, Arc<dyn Any + Send + Sync>)>,
+  // The key of each cached spec by the tick it was last used at, oldest
+  // first.
+  order:    BTreeMap<u64, IppResizeKey>,
+}
+
+impl IppResizeCache {
+  pub fn new(capacity: usize) -> IppResizeCache {
+    IppResizeCache{
+      cap:      capacity,
+      tick:     0,
+      specs:    HashMap::new(),
+      order:    BTreeMap::new(),
+    }
+  }
+
+  pub fn capacity(&self) -> usize {
+    self.cap
+  }
+
+  pub fn len(&self) -> usize {
+    self.specs.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.specs.is_empty()
+  }
+
+  pub fn clear(&mut self) {
+    self.specs.clear();
+    self.order.clear();
+  }
+
+  /// Returns the cached spec for the given plan, creating it on a miss.
+  pub fn get<T>(&mut self, kind: IppImageResizeKind, layout: IppChannelLayout, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Arc<IppResizeSpec<T>>> where T: IppPixel {
+    self.get_with_antialiasing(kind, layout, false, src_width, src_height, dst_width, dst_height)
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  pub fn get_with_antialiasing<T>(&mut self, kind: IppImageResizeKind, layout: IppChannelLayout, antialiasing: bool, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Arc<IppResizeSpec<T>>> where T: IppPixel {
+    let key = IppResizeKey{
+      kind:     kind_key(kind),
+      aa:       antialiasing,
+      pixel:    TypeId::of::<T>(),
+      layout:   layout,
+      src:      (src_width, src_height),
+      dst:      (dst_width, dst_height),
+    };
+    self.tick += 1;
+    if let Some(entry) = self.specs.get_mut(&key) {
+      self.order.remove(&entry.0);
+      self.order.insert(self.tick, key);
+      entry.0 = self.tick;
+      // The key includes the pixel type, so the downcast cannot fail.
+      return Ok(entry.1.clone().downcast::<IppResizeSpec<T>>().unwrap());
+    }
+    let spec = Arc::new(IppResizeSpec::<T>::create_with_antialiasing(kind, layout, antialiasing, src_width, src_height, dst_width, dst_height)?);
+    if self.cap == 0 {
+      return Ok(spec);
+    }
+    if self.specs.len() >= self.cap {
+      if let Some((_, lru_key)) = self.order.pop_first() {
+        self.specs.remove(&lru_key);
+      }
+    }
+    self.specs.insert(key, (self.tick, spec.clone()));
+    self.order.insert(self.tick, key);
+    Ok(spec)
+  }
+
+  /// Creates a resizer which shares the cached spec and owns a fresh work
+  /// buffer.
+  pub fn resizer<T>(&mut self, kind: IppImageResizeKind, layout: IppChannelLayout, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<IppImageResize<T>> where T: IppPixel {
+    let spec = self.get(kind, layout, src_width, src_height, dst_width, dst_height)?;
+    IppImageResize::from_spec(spec)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn evicts_least_recently_used() {
+    let c1 = IppChannelLayout::C1;
+    let linear = IppImageResizeKind::Linear;
+    let mut cache = IppResizeCache::new(2);
+    let a = cache.get::<u8>(linear, c1, 8, 8, 4, 4).unwrap();
+    let b = cache.get::<u8>(linear, c1, 8, 8, 2, 2).unwrap();
+    assert!(Arc::ptr_eq(&a, &cache.get::<u8>(linear, c1, 8, 8, 4, 4).unwrap()));
+    cache.get::<u8>(linear, c1, 8, 8, 3, 3).unwrap();
+    assert_eq!(cache.len(), 2);
+    assert!(Arc::ptr_eq(&a, &cache.get::<u8>(linear, c1, 8, 8, 4, 4).unwrap()));
+    assert!(!Arc::ptr_eq(&b, &cache.get::<u8>(linear, c1, 8, 8, 2, 2).unwrap()));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.order.len(), 2);
+  }
+}
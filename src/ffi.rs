@@ -2,22 +2,67 @@
 
 use libc::*;
 
-//pub struct IppStatus(pub c_int);
+use std::ops::{BitOr};
 
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub enum IppStatus {
-  IppStsNoErr = 0,
-  // TODO
-}
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct IppStatus(pub c_int);
 
+#[allow(non_upper_case_globals)]
 impl IppStatus {
+  pub const IppStsNotSupportedModeErr:          IppStatus = IppStatus(-9999);
+  pub const IppStsCpuNotSupportedErr:           IppStatus = IppStatus(-9998);
+  pub const IppStsInplaceModeNotSupportedErr:   IppStatus = IppStatus(-9997);
+  pub const IppStsWarpTransformTypeErr:         IppStatus = IppStatus(-233);
+  pub const IppStsExceededSizeErr:              IppStatus = IppStatus(-232);
+  pub const IppStsWarpDirectionErr:             IppStatus = IppStatus(-231);
+  pub const IppStsMisalignedOffsetErr:          IppStatus = IppStatus(-227);
+  pub const IppStsBorderErr:                    IppStatus = IppStatus(-225);
+  pub const IppStsNotEvenStepErr:               IppStatus = IppStatus(-108);
+  pub const IppStsNumChannelsErr:               IppStatus = IppStatus(-53);
+  pub const IppStsChannelErr:                   IppStatus = IppStatus(-47);
+  pub const IppStsAnchorErr:                    IppStatus = IppStatus(-34);
+  pub const IppStsMaskSizeErr:                  IppStatus = IppStatus(-33);
+  pub const IppStsResizeFactorErr:              IppStatus = IppStatus(-23);
+  pub const IppStsInterpolationErr:             IppStatus = IppStatus(-22);
+  pub const IppStsMirrorFlipErr:                IppStatus = IppStatus(-21);
+  pub const IppStsContextMatchErr:              IppStatus = IppStatus(-17);
+  pub const IppStsStepErr:                      IppStatus = IppStatus(-14);
+  pub const IppStsDataTypeErr:                  IppStatus = IppStatus(-12);
+  pub const IppStsOutOfRangeErr:                IppStatus = IppStatus(-11);
+  pub const IppStsDivByZeroErr:                 IppStatus = IppStatus(-10);
+  pub const IppStsMemAllocErr:                  IppStatus = IppStatus(-9);
+  pub const IppStsNullPtrErr:                   IppStatus = IppStatus(-8);
+  pub const IppStsRangeErr:                     IppStatus = IppStatus(-7);
+  pub const IppStsSizeErr:                      IppStatus = IppStatus(-6);
+  pub const IppStsBadArgErr:                    IppStatus = IppStatus(-5);
+  pub const IppStsNoMemErr:                     IppStatus = IppStatus(-4);
+  pub const IppStsErr:                          IppStatus = IppStatus(-2);
+  pub const IppStsNoErr:                        IppStatus = IppStatus(0);
+  pub const IppStsNoOperation:                  IppStatus = IppStatus(1);
+  pub const IppStsMisalignedBuf:                IppStatus = IppStatus(2);
+  pub const IppStsSqrtNegArg:                   IppStatus = IppStatus(3);
+  pub const IppStsInvZero:                      IppStatus = IppStatus(4);
+  pub const IppStsEvenMedianMaskSize:           IppStatus = IppStatus(5);
+  pub const IppStsDivByZero:                    IppStatus = IppStatus(6);
+  pub const IppStsLnZeroArg:                    IppStatus = IppStatus(7);
+  pub const IppStsLnNegArg:                     IppStatus = IppStatus(8);
+  pub const IppStsNanArg:                       IppStatus = IppStatus(9);
+
+  pub fn code(self) -> i32 {
+    self.0
+  }
+
   pub fn is_ok(self) -> bool {
-    self as i32 == 0
+    self.0 == 0
   }
 
   pub fn is_err(self) -> bool {
-    !self.is_ok()
+    self.0 < 0
+  }
+
+  pub fn is_warning(self) -> bool {
+    self.0 > 0
   }
 }
 
@@ -70,27 +115,87 @@ pub struct IppiBorderSize {
   pub border_bottom:    u32,
 }
 
+// IPP border types are flags: the `ippBorderInMem*` values may be or'ed with
+// a base border type such as `ippBorderRepl` or `ippBorderConst`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct IppiBorderType(pub c_int);
+
+#[allow(non_upper_case_globals)]
+impl IppiBorderType {
+  pub const ippBorderRepl:          IppiBorderType = IppiBorderType(1);
+  pub const ippBorderWrap:          IppiBorderType = IppiBorderType(2);
+  pub const ippBorderMirror:        IppiBorderType = IppiBorderType(3);
+  pub const ippBorderMirrorR:       IppiBorderType = IppiBorderType(4);
+  pub const ippBorderDefault:       IppiBorderType = IppiBorderType(5);
+  pub const ippBorderConst:         IppiBorderType = IppiBorderType(6);
+  pub const ippBorderTransp:        IppiBorderType = IppiBorderType(7);
+  pub const ippBorderInMemTop:      IppiBorderType = IppiBorderType(0x0010);
+  pub const ippBorderInMemBottom:   IppiBorderType = IppiBorderType(0x0020);
+  pub const ippBorderInMemLeft:     IppiBorderType = IppiBorderType(0x0040);
+  pub const ippBorderInMemRight:    IppiBorderType = IppiBorderType(0x0080);
+  pub const ippBorderInMem:         IppiBorderType = IppiBorderType(0x00f0);
+}
+
+impl BitOr for IppiBorderType {
+  type Output = IppiBorderType;
+
+  fn bitor(self, rhs: IppiBorderType) -> IppiBorderType {
+    IppiBorderType(self.0 | rhs.0)
+  }
+}
+
 #[derive(Clone, Copy)]
 #[repr(C)]
-pub enum IppiBorderType {
-  ippBorderRepl         = 1,
-  ippBorderWrap         = 2,
-  ippBorderMirror       = 3,
-  ippBorderMirrorR      = 4,
-  ippBorderDefault      = 5,
-  ippBorderConst        = 6,
-  ippBorderTransp       = 7,
-  ippBorderInMemTop     = 0x0010,
-  ippBorderInMemBottom  = 0x0020,
-  ippBorderInMemLeft    = 0x0040,
-  ippBorderInMemRight   = 0x0080,
-  ippBorderInMem        = 0x00f0,
+pub enum IppDataType {
+  ippUndef  = -1,
+  ipp1u     = 0,
+  ipp8u     = 1,
+  ipp8uc    = 2,
+  ipp8s     = 3,
+  ipp8sc    = 4,
+  ipp16u    = 5,
+  ipp16uc   = 6,
+  ipp16s    = 7,
+  ipp16sc   = 8,
+  ipp32u    = 9,
+  ipp32uc   = 10,
+  ipp32s    = 11,
+  ipp32sc   = 12,
+  ipp32f    = 13,
+  ipp32fc   = 14,
+  ipp64u    = 15,
+  ipp64uc   = 16,
+  ipp64s    = 17,
+  ipp64sc   = 18,
+  ipp64f    = 19,
+  ipp64fc   = 20,
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub enum IppiWarpDirection {
+  ippWarpForward    = 0,
+  ippWarpBackward   = 1,
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub enum IppiAxis {
+  ippAxsHorizontal  = 0,
+  ippAxsVertical    = 1,
+  ippAxsBoth        = 2,
+  ippAxs45          = 3,
+  ippAxs135         = 4,
 }
 
 pub type IppiResizeSpec_32f = u8;
+pub type IppiResizeSpec_64f = u8;
+pub type IppiWarpSpec = u8;
 
 #[link(name = "ippcore")]
 extern "C" {
+  pub fn ippGetStatusString(status: IppStatus) -> *const c_char;
 }
 
 #[link(name = "ipps")]
@@ -102,28 +207,350 @@ extern "C" {
 #[link(name = "ippi")]
 extern "C" {
   pub fn ippiMalloc_8u_C1(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u8;
+  pub fn ippiMalloc_8u_C3(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u8;
+  pub fn ippiMalloc_8u_C4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u8;
+  pub fn ippiMalloc_8u_AC4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u8;
   pub fn ippiMalloc_32f_C1(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut f32;
+  pub fn ippiMalloc_32f_C3(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut f32;
+  pub fn ippiMalloc_32f_C4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut f32;
+  pub fn ippiMalloc_32f_AC4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut f32;
+  pub fn ippiMalloc_16u_C1(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u16;
+  pub fn ippiMalloc_16u_C3(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u16;
+  pub fn ippiMalloc_16u_C4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u16;
+  pub fn ippiMalloc_16u_AC4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut u16;
+  pub fn ippiMalloc_16s_C1(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut i16;
+  pub fn ippiMalloc_16s_C3(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut i16;
+  pub fn ippiMalloc_16s_C4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut i16;
+  pub fn ippiMalloc_16s_AC4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut i16;
+  pub fn ippiMalloc_64f_C1(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut f64;
+  pub fn ippiMalloc_64f_C3(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut f64;
+  pub fn ippiMalloc_64f_C4(width_pixels: c_int, height_pixels: c_int, pitch: *mut c_int) -> *mut f64;
   pub fn ippiFree(ptr: *mut c_void);
 
   pub fn ippiCopy_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_8u_AC4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
   pub fn ippiCopy_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_32f_AC4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16u_C1R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16u_C3R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16u_C4R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16u_AC4R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16s_C1R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16s_C3R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16s_C4R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiCopy_16s_AC4R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+
+  pub fn ippiSet_8u_C1R(value: u8, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_8u_C3R(value: *const u8, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_8u_C4R(value: *const u8, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_8u_AC4R(value: *const u8, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_32f_C1R(value: f32, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_32f_C3R(value: *const f32, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_32f_C4R(value: *const f32, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_32f_AC4R(value: *const f32, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16u_C1R(value: u16, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16u_C3R(value: *const u16, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16u_C4R(value: *const u16, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16u_AC4R(value: *const u16, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16s_C1R(value: i16, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16s_C3R(value: *const i16, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16s_C4R(value: *const i16, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSet_16s_AC4R(value: *const i16, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+
+  pub fn ippiResizeAntialiasingLinearInit(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasingCubicInit(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasingLanczosInit(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
 
   pub fn ippiResizeGetSize_8u(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
   pub fn ippiResizeLinearInit_8u(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
   pub fn ippiResizeCubicInit_8u(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
   pub fn ippiResizeLanczosInit_8u(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearestInit_8u(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeSuperInit_8u(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
   pub fn ippiResizeGetBufferSize_8u(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeGetSrcOffset_8u(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus;
   pub fn ippiResizeGetBorderSize_8u(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus;
   pub fn ippiResizeLinear_8u_C1R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_8u_C3R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_8u_C4R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
   pub fn ippiResizeCubic_8u_C1R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_8u_C3R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_8u_C4R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
   pub fn ippiResizeLanczos_8u_C1R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_8u_C3R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_8u_C4R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_8u_C1R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_8u_C3R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_8u_C4R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_8u_C1R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_8u_C3R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_8u_C4R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasing_8u_C1R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasing_8u_C3R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasing_8u_C4R(src: *const u8, src_pitch: i32, dst: *mut u8, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u8, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
 
   pub fn ippiResizeGetSize_32f(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
   pub fn ippiResizeLinearInit_32f(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
   pub fn ippiResizeCubicInit_32f(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
   pub fn ippiResizeLanczosInit_32f(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearestInit_32f(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeSuperInit_32f(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
   pub fn ippiResizeGetBufferSize_32f(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeGetSrcOffset_32f(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus;
+  pub fn ippiResizeGetBorderSize_32f(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus;
   pub fn ippiResizeLinear_32f_C1R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_32f_C3R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_32f_C4R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
   pub fn ippiResizeCubic_32f_C1R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_32f_C3R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_32f_C4R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
   pub fn ippiResizeLanczos_32f_C1R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_32f_C3R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_32f_C4R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_32f_C1R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_32f_C3R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_32f_C4R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_32f_C1R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_32f_C3R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_32f_C4R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasing_32f_C1R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasing_32f_C3R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeAntialiasing_32f_C4R(src: *const f32, src_pitch: i32, dst: *mut f32, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f32, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+
+  pub fn ippiResizeGetSize_16u(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeLinearInit_16u(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeCubicInit_16u(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczosInit_16u(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearestInit_16u(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeSuperInit_16u(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeGetBufferSize_16u(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeGetSrcOffset_16u(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus;
+  pub fn ippiResizeGetBorderSize_16u(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus;
+  pub fn ippiResizeLinear_16u_C1R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_16u_C3R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_16u_C4R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_16u_C1R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_16u_C3R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_16u_C4R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_16u_C1R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_16u_C3R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_16u_C4R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const u16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_16u_C1R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_16u_C3R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_16u_C4R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_16u_C1R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_16u_C3R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_16u_C4R(src: *const u16, src_pitch: i32, dst: *mut u16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+
+  pub fn ippiResizeGetSize_16s(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeLinearInit_16s(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeCubicInit_16s(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczosInit_16s(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearestInit_16s(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeSuperInit_16s(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  pub fn ippiResizeGetBufferSize_16s(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeGetSrcOffset_16s(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus;
+  pub fn ippiResizeGetBorderSize_16s(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus;
+  pub fn ippiResizeLinear_16s_C1R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_16s_C3R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_16s_C4R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_16s_C1R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_16s_C3R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_16s_C4R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_16s_C1R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_16s_C3R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLanczos_16s_C4R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const i16, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_16s_C1R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_16s_C3R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeNearest_16s_C4R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_16s_C1R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_16s_C3R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeSuper_16s_C4R(src: *const i16, src_pitch: i32, dst: *mut i16, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+
+  pub fn ippiResizeGetSize_64f(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeLinearInit_64f(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_64f) -> IppStatus;
+  pub fn ippiResizeCubicInit_64f(src_size: IppiSize, dst_size: IppiSize, value_b: f64, value_c: f64, spec: *mut IppiResizeSpec_64f, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeGetBufferSize_64f(spec: *const IppiResizeSpec_64f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiResizeGetSrcOffset_64f(spec: *const IppiResizeSpec_64f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus;
+  pub fn ippiResizeGetBorderSize_64f(spec: *const IppiResizeSpec_64f, border_size: *mut IppiBorderSize) -> IppStatus;
+  pub fn ippiResizeLinear_64f_C1R(src: *const f64, src_pitch: i32, dst: *mut f64, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f64, spec: *const IppiResizeSpec_64f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_64f_C3R(src: *const f64, src_pitch: i32, dst: *mut f64, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f64, spec: *const IppiResizeSpec_64f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeLinear_64f_C4R(src: *const f64, src_pitch: i32, dst: *mut f64, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f64, spec: *const IppiResizeSpec_64f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_64f_C1R(src: *const f64, src_pitch: i32, dst: *mut f64, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f64, spec: *const IppiResizeSpec_64f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_64f_C3R(src: *const f64, src_pitch: i32, dst: *mut f64, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f64, spec: *const IppiResizeSpec_64f, buf: *mut u8) -> IppStatus;
+  pub fn ippiResizeCubic_64f_C4R(src: *const f64, src_pitch: i32, dst: *mut f64, dst_pitch: i32, dst_offset: IppiPoint, dst_size: IppiSize, border: IppiBorderType, border_value: *const f64, spec: *const IppiResizeSpec_64f, buf: *mut u8) -> IppStatus;
+}
+
+pub type IppiPyramidDownState = u8;
+pub type IppiPyramidUpState = u8;
+
+pub const IPPI_INTER_NN: c_int = 1;
+pub const IPPI_INTER_LINEAR: c_int = 2;
+pub const IPPI_INTER_CUBIC: c_int = 4;
+
+#[link(name = "ippi")]
+extern "C" {
+  pub fn ippiAdd_32f_C1IR(src: *const f32, src_pitch: c_int, src_dst: *mut f32, src_dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiAdd_32f_C3IR(src: *const f32, src_pitch: c_int, src_dst: *mut f32, src_dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSub_32f_C1R(src1: *const f32, src1_pitch: c_int, src2: *const f32, src2_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+  pub fn ippiSub_32f_C3R(src1: *const f32, src1_pitch: c_int, src2: *const f32, src2_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize) -> IppStatus;
+}
+
+#[link(name = "ippcv")]
+extern "C" {
+  pub fn ippiGetPyramidDownROI(src_roi: IppiSize, dst_roi: *mut IppiSize, rate: f32) -> IppStatus;
+  pub fn ippiGetPyramidUpROI(src_roi: IppiSize, dst_roi_min: *mut IppiSize, dst_roi_max: *mut IppiSize, rate: f32) -> IppStatus;
+
+  pub fn ippiPyramidLayerDownGetSize_8u_C1R(src_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerDownGetSize_8u_C3R(src_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerDownGetSize_32f_C1R(src_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerDownGetSize_32f_C3R(src_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerDownInit_8u_C1R(state: *mut *mut IppiPyramidDownState, src_roi: IppiSize, rate: f32, kernel: *const i16, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerDownInit_8u_C3R(state: *mut *mut IppiPyramidDownState, src_roi: IppiSize, rate: f32, kernel: *const i16, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerDownInit_32f_C1R(state: *mut *mut IppiPyramidDownState, src_roi: IppiSize, rate: f32, kernel: *const f32, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerDownInit_32f_C3R(state: *mut *mut IppiPyramidDownState, src_roi: IppiSize, rate: f32, kernel: *const f32, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerDown_8u_C1R(src: *const u8, src_pitch: c_int, src_roi: IppiSize, dst: *mut u8, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidDownState) -> IppStatus;
+  pub fn ippiPyramidLayerDown_8u_C3R(src: *const u8, src_pitch: c_int, src_roi: IppiSize, dst: *mut u8, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidDownState) -> IppStatus;
+  pub fn ippiPyramidLayerDown_32f_C1R(src: *const f32, src_pitch: c_int, src_roi: IppiSize, dst: *mut f32, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidDownState) -> IppStatus;
+  pub fn ippiPyramidLayerDown_32f_C3R(src: *const f32, src_pitch: c_int, src_roi: IppiSize, dst: *mut f32, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidDownState) -> IppStatus;
+
+  pub fn ippiPyramidLayerUpGetSize_8u_C1R(dst_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerUpGetSize_8u_C3R(dst_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerUpGetSize_32f_C1R(dst_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerUpGetSize_32f_C3R(dst_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  pub fn ippiPyramidLayerUpInit_8u_C1R(state: *mut *mut IppiPyramidUpState, dst_roi: IppiSize, rate: f32, kernel: *const i16, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerUpInit_8u_C3R(state: *mut *mut IppiPyramidUpState, dst_roi: IppiSize, rate: f32, kernel: *const i16, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerUpInit_32f_C1R(state: *mut *mut IppiPyramidUpState, dst_roi: IppiSize, rate: f32, kernel: *const f32, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerUpInit_32f_C3R(state: *mut *mut IppiPyramidUpState, dst_roi: IppiSize, rate: f32, kernel: *const f32, ker_size: c_int, mode: c_int, state_buf: *mut u8) -> IppStatus;
+  pub fn ippiPyramidLayerUp_8u_C1R(src: *const u8, src_pitch: c_int, src_roi: IppiSize, dst: *mut u8, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidUpState) -> IppStatus;
+  pub fn ippiPyramidLayerUp_8u_C3R(src: *const u8, src_pitch: c_int, src_roi: IppiSize, dst: *mut u8, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidUpState) -> IppStatus;
+  pub fn ippiPyramidLayerUp_32f_C1R(src: *const f32, src_pitch: c_int, src_roi: IppiSize, dst: *mut f32, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidUpState) -> IppStatus;
+  pub fn ippiPyramidLayerUp_32f_C3R(src: *const f32, src_pitch: c_int, src_roi: IppiSize, dst: *mut f32, dst_pitch: c_int, dst_roi: IppiSize, state: *mut IppiPyramidUpState) -> IppStatus;
+}
+
+#[link(name = "ippi")]
+extern "C" {
+  pub fn ippiWarpGetBufferSize(spec: *const IppiWarpSpec, dst_size: IppiSize, buf_size: *mut c_int) -> IppStatus;
+
+  pub fn ippiWarpAffineGetSize(src_size: IppiSize, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], interpolation: IppiInterpolationType, direction: IppiWarpDirection, border: IppiBorderType, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiWarpAffineNearestInit(src_size: IppiSize, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], direction: IppiWarpDirection, num_channels: c_int, border: IppiBorderType, border_value: *const f64, smooth_edge: c_int, spec: *mut IppiWarpSpec) -> IppStatus;
+  pub fn ippiWarpAffineLinearInit(src_size: IppiSize, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], direction: IppiWarpDirection, num_channels: c_int, border: IppiBorderType, border_value: *const f64, smooth_edge: c_int, spec: *mut IppiWarpSpec) -> IppStatus;
+  pub fn ippiWarpAffineCubicInit(src_size: IppiSize, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], direction: IppiWarpDirection, num_channels: c_int, value_b: f64, value_c: f64, border: IppiBorderType, border_value: *const f64, smooth_edge: c_int, spec: *mut IppiWarpSpec, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineNearest_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineNearest_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineNearest_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineLinear_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineLinear_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineLinear_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineCubic_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineCubic_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineCubic_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineNearest_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineNearest_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineNearest_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineLinear_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineLinear_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineLinear_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineCubic_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineCubic_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpAffineCubic_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveGetSize(src_size: IppiSize, src_roi: IppiRect, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], interpolation: IppiInterpolationType, direction: IppiWarpDirection, border: IppiBorderType, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
+  pub fn ippiWarpPerspectiveNearestInit(src_size: IppiSize, src_roi: IppiRect, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], direction: IppiWarpDirection, num_channels: c_int, border: IppiBorderType, border_value: *const f64, smooth_edge: c_int, spec: *mut IppiWarpSpec) -> IppStatus;
+  pub fn ippiWarpPerspectiveLinearInit(src_size: IppiSize, src_roi: IppiRect, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], direction: IppiWarpDirection, num_channels: c_int, border: IppiBorderType, border_value: *const f64, smooth_edge: c_int, spec: *mut IppiWarpSpec) -> IppStatus;
+  pub fn ippiWarpPerspectiveCubicInit(src_size: IppiSize, src_roi: IppiRect, dst_size: IppiSize, data_type: IppDataType, coeffs: *const [f64; 3], direction: IppiWarpDirection, num_channels: c_int, value_b: f64, value_c: f64, border: IppiBorderType, border_value: *const f64, smooth_edge: c_int, spec: *mut IppiWarpSpec, init_buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveNearest_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveNearest_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveNearest_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveLinear_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveLinear_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveLinear_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveCubic_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveCubic_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveCubic_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveNearest_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveNearest_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveNearest_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveLinear_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveLinear_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveLinear_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveCubic_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveCubic_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+  pub fn ippiWarpPerspectiveCubic_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_offset: IppiPoint, dst_roi_size: IppiSize, spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+}
+
+#[link(name = "ippi")]
+extern "C" {
+  pub fn ippiRemap_8u_C1R(src: *const u8, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+  pub fn ippiRemap_8u_C3R(src: *const u8, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+  pub fn ippiRemap_8u_C4R(src: *const u8, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+  pub fn ippiRemap_8u_AC4R(src: *const u8, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut u8, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+  pub fn ippiRemap_32f_C1R(src: *const f32, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+  pub fn ippiRemap_32f_C3R(src: *const f32, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+  pub fn ippiRemap_32f_C4R(src: *const f32, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+  pub fn ippiRemap_32f_AC4R(src: *const f32, src_size: IppiSize, src_pitch: c_int, src_roi: IppiRect, x_map: *const f32, x_map_pitch: c_int, y_map: *const f32, y_map_pitch: c_int, dst: *mut f32, dst_pitch: c_int, dst_roi_size: IppiSize, interpolation: c_int) -> IppStatus;
+}
+
+#[link(name = "ippi")]
+extern "C" {
+  pub fn ippiMirror_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_8u_AC4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_8u_C1IR(src_dst: *mut u8, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_8u_C3IR(src_dst: *mut u8, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_8u_C4IR(src_dst: *mut u8, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_8u_AC4IR(src_dst: *mut u8, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_C1R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_C3R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_C4R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_AC4R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_C1IR(src_dst: *mut u16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_C3IR(src_dst: *mut u16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_C4IR(src_dst: *mut u16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16u_AC4IR(src_dst: *mut u16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_C1R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_C3R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_C4R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_AC4R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_C1IR(src_dst: *mut i16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_C3IR(src_dst: *mut i16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_C4IR(src_dst: *mut i16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_16s_AC4IR(src_dst: *mut i16, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_AC4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_C1IR(src_dst: *mut f32, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_C3IR(src_dst: *mut f32, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_C4IR(src_dst: *mut f32, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+  pub fn ippiMirror_32f_AC4IR(src_dst: *mut f32, src_dst_pitch: c_int, roi_size: IppiSize, flip: IppiAxis) -> IppStatus;
+
+  pub fn ippiTranspose_8u_C1R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_8u_C3R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_8u_C4R(src: *const u8, src_pitch: c_int, dst: *mut u8, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_8u_C1IR(src_dst: *mut u8, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_8u_C3IR(src_dst: *mut u8, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_8u_C4IR(src_dst: *mut u8, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16u_C1R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16u_C3R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16u_C4R(src: *const u16, src_pitch: c_int, dst: *mut u16, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16u_C1IR(src_dst: *mut u16, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16u_C3IR(src_dst: *mut u16, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16u_C4IR(src_dst: *mut u16, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16s_C1R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16s_C3R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16s_C4R(src: *const i16, src_pitch: c_int, dst: *mut i16, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16s_C1IR(src_dst: *mut i16, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16s_C3IR(src_dst: *mut i16, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_16s_C4IR(src_dst: *mut i16, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_32f_C1R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_32f_C3R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_32f_C4R(src: *const f32, src_pitch: c_int, dst: *mut f32, dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_32f_C1IR(src_dst: *mut f32, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_32f_C3IR(src_dst: *mut f32, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
+  pub fn ippiTranspose_32f_C4IR(src_dst: *mut f32, src_dst_pitch: c_int, src_roi: IppiSize) -> IppStatus;
 }
@@ -0,0 +1,147 @@
+use {IppImageResizeExt, IppImageResizeKind, IppPixel, IppResizeCache, IppResult, ipp_size};
+use view::{IppImageView, IppImageViewMut, ipp_try_fill_image};
+
+use std::cmp::{max, min};
+
+/// The mapping from src to dst coordinates applied by an aspect preserving
+/// resize, in continuous pixel coordinates where pixel (i, j) covers
+/// [i, i + 1) x [j, j + 1).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct IppResizeTransform {
+  pub scale_x:  f64,
+  pub scale_y:  f64,
+  pub offset_x: f64,
+  pub offset_y: f64,
+}
+
+impl IppResizeTransform {
+  /// Maps a src point to the dst image.
+  pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+    (x * self.scale_x + self.offset_x, y * self.scale_y + self.offset_y)
+  }
+
+  /// Maps a dst point back to the src image.
+  pub fn invert(&self, x: f64, y: f64) -> (f64, f64) {
+    ((x - self.offset_x) / self.scale_x, (y - self.offset_y) / self.scale_y)
+  }
+}
+
+// Rounds to at least 1, but never beyond `max_size`.
+fn round_size(size: f64, max_size: usize) -> usize {
+  min(max(size.round() as usize, 1), max_size)
+}
+
+/// The largest size with the aspect ratio of the src size which fits inside
+/// the dst size.
+pub fn ipp_fit_size(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> (usize, usize) {
+  let scale_x = dst_width as f64 / src_width as f64;
+  let scale_y = dst_height as f64 / src_height as f64;
+  if scale_x <= scale_y {
+    (dst_width, round_size(src_height as f64 * scale_x, dst_height))
+  } else {
+    (round_size(src_width as f64 * scale_y, dst_width), dst_height)
+  }
+}
+
+/// The largest centered src rectangle, as `(x, y, width, height)`, with the
+/// aspect ratio of the dst size.
+pub fn ipp_fill_crop(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> (usize, usize, usize, usize) {
+  let scale_x = dst_width as f64 / src_width as f64;
+  let scale_y = dst_height as f64 / src_height as f64;
+  let (crop_width, crop_height) = if scale_x >= scale_y {
+    (src_width, round_size(dst_height as f64 / scale_x, src_height))
+  } else {
+    (round_size(dst_width as f64 / scale_y, src_width), src_height)
+  };
+  ((src_width - crop_width) / 2, (src_height - crop_height) / 2, crop_width, crop_height)
+}
+
+pub fn ipp_resize_fit<T>(cache: &mut IppResizeCache, kind: IppImageResizeKind, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, pad: [T; 4]) -> IppResizeTransform where T: IppPixel {
+  ipp_try_resize_fit(cache, kind, src, dst, pad).unwrap()
+}
+
+/// Letterboxes `src` into `dst`: resizes it to the largest size that fits
+/// while keeping its aspect ratio, centers it, and fills the rest of `dst`
+/// with `pad`.
+pub fn ipp_try_resize_fit<T>(cache: &mut IppResizeCache, kind: IppImageResizeKind, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, pad: [T; 4]) -> IppResult<IppResizeTransform> where T: IppPixel {
+  ipp_size(src.width(), src.height())?;
+  ipp_size(dst.width(), dst.height())?;
+  let (fit_width, fit_height) = ipp_fit_size(src.width(), src.height(), dst.width(), dst.height());
+  let fit_x = (dst.width() - fit_width) / 2;
+  let fit_y = (dst.height() - fit_height) / 2;
+  let mut resize = cache.resizer::<T>(kind, src.layout(), src.width(), src.height(), fit_width, fit_height)?;
+  if (fit_width, fit_height) != (dst.width(), dst.height()) {
+    ipp_try_fill_image(dst, pad)?;
+  }
+  resize.try_resize_view(src, &mut dst.roi_mut(fit_x, fit_y, fit_width, fit_height)?)?;
+  let scale_x = fit_width as f64 / src.width() as f64;
+  let scale_y = fit_height as f64 / src.height() as f64;
+  Ok(IppResizeTransform{
+    scale_x:  scale_x,
+    scale_y:  scale_y,
+    offset_x: fit_x as f64,
+    offset_y: fit_y as f64,
+  })
+}
+
+pub fn ipp_resize_fill<T>(cache: &mut IppResizeCache, kind: IppImageResizeKind, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResizeTransform where T: IppPixel {
+  ipp_try_resize_fill(cache, kind, src, dst).unwrap()
+}
+
+/// Fills `dst` with `src` while keeping its aspect ratio, cropping the excess
+/// of `src` evenly from both sides. With a square `dst` of side N this is
+/// "resize the shorter side to N, then center crop".
+pub fn ipp_try_resize_fill<T>(cache: &mut IppResizeCache, kind: IppImageResizeKind, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<IppResizeTransform> where T: IppPixel {
+  ipp_size(src.width(), src.height())?;
+  ipp_size(dst.width(), dst.height())?;
+  let (crop_x, crop_y, crop_width, crop_height) = ipp_fill_crop(src.width(), src.height(), dst.width(), dst.height());
+  let mut resize = cache.resizer::<T>(kind, src.layout(), crop_width, crop_height, dst.width(), dst.height())?;
+  resize.try_resize_view(&src.roi(crop_x, crop_y, crop_width, crop_height)?, dst)?;
+  let scale_x = dst.width() as f64 / crop_width as f64;
+  let scale_y = dst.height() as f64 / crop_height as f64;
+  Ok(IppResizeTransform{
+    scale_x:  scale_x,
+    scale_y:  scale_y,
+    offset_x: -(crop_x as f64) * scale_x,
+    offset_y: -(crop_y as f64) * scale_y,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fit_size_landscape_portrait_square() {
+    assert_eq!(ipp_fit_size(200, 100, 100, 100), (100, 50));
+    assert_eq!(ipp_fit_size(100, 200, 100, 100), (50, 100));
+    assert_eq!(ipp_fit_size(50, 50, 100, 80), (80, 80));
+    assert_eq!(ipp_fit_size(640, 480, 320, 240), (320, 240));
+  }
+
+  #[test]
+  fn fit_size_never_exceeds_dst() {
+    assert_eq!(ipp_fit_size(1000, 1, 10, 10), (10, 1));
+    assert_eq!(ipp_fit_size(1, 1000, 10, 10), (1, 10));
+  }
+
+  #[test]
+  fn fill_crop_landscape_portrait_square() {
+    assert_eq!(ipp_fill_crop(200, 100, 100, 100), (50, 0, 100, 100));
+    assert_eq!(ipp_fill_crop(100, 200, 100, 100), (0, 50, 100, 100));
+    assert_eq!(ipp_fill_crop(100, 100, 200, 100), (0, 25, 100, 50));
+    assert_eq!(ipp_fill_crop(100, 100, 50, 50), (0, 0, 100, 100));
+  }
+
+  #[test]
+  fn resize_transform_invert() {
+    let t = IppResizeTransform{
+      scale_x:  0.5,
+      scale_y:  0.25,
+      offset_x: 3.0,
+      offset_y: 7.0,
+    };
+    assert_eq!(t.apply(10.0, 20.0), (8.0, 12.0));
+    assert_eq!(t.invert(8.0, 12.0), (10.0, 20.0));
+  }
+}
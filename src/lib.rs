@@ -1,13 +1,283 @@
+// Struct literals spell out `field: field` so that the values line up.
+#![allow(clippy::redundant_field_names)]
+
 extern crate icc_link;
 extern crate libc;
 
 use ffi::*;
 
+use libc::{c_int};
+
+use std::cell::{Cell};
+use std::cmp::{max, min};
+use std::error::{Error};
+use std::ffi::{CStr};
+use std::fmt;
 use std::marker::{PhantomData};
+use std::mem::{size_of, take};
 //use std::ops::{Deref, DerefMut};
 use std::ptr::{null};
+use std::sync::{Arc};
+use std::thread;
+
+pub use cache::{IppResizeCache};
+pub use fit::{IppResizeTransform, ipp_fill_crop, ipp_fit_size, ipp_resize_fill, ipp_resize_fit, ipp_try_resize_fill, ipp_try_resize_fit};
+pub use orient::{IppMirrorAxis, IppOrientation, IppRotation, ipp_mirror_image, ipp_mirror_image_inplace, ipp_orient_image, ipp_rotate_image, ipp_transpose_image, ipp_transpose_image_inplace, ipp_try_mirror_image, ipp_try_mirror_image_inplace, ipp_try_orient_image, ipp_try_rotate_image, ipp_try_transpose_image, ipp_try_transpose_image_inplace};
+pub use pixel::{IppPixel};
+pub use pyramid::{IppGaussianPyramid, IppLaplacianPyramid, IppPyramidLayerDown, IppPyramidLayerUp, IppPyramidPixel};
+pub use remap::{IppCameraIntrinsics, IppRadialTangential, IppRemap, IppRemapKind, ipp_build_remap, ipp_undistort_maps};
+pub use view::{IppImageView, IppImageViewMut, ipp_copy_image, ipp_fill_image, ipp_try_copy_image, ipp_try_fill_image};
+pub use warp::{IppWarpAffine, IppWarpBorder, IppWarpKind, IppWarpPerspective, IppWarpPixel, ipp_affine_rotation, ipp_perspective_from_points};
 
 pub mod ffi;
+mod cache;
+mod fit;
+mod orient;
+mod pixel;
+mod pyramid;
+mod remap;
+mod view;
+mod warp;
+
+pub type IppResult<T> = Result<T, IppError>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IppError {
+  // Errors reported by IPP (negative status codes).
+  NotSupportedMode,
+  CpuNotSupported,
+  InplaceModeNotSupported,
+  Unspecified,
+  NoMem,
+  BadArg,
+  Size,
+  Range,
+  NullPtr,
+  MemAlloc,
+  DivByZero,
+  OutOfRange,
+  DataType,
+  Step,
+  ContextMatch,
+  MirrorFlip,
+  Interpolation,
+  ResizeFactor,
+  MaskSize,
+  Anchor,
+  Channel,
+  NumChannels,
+  NotEvenStep,
+  Border,
+  MisalignedOffset,
+  WarpDirection,
+  ExceededSize,
+  WarpTransformType,
+  UnknownStatus(i32),
+  // Errors detected by this crate before calling into IPP.
+  Alloc,
+  BufferLen{expected: usize, actual: usize},
+  OutOfBounds,
+  InvalidArg(&'static str),
+  InvalidSize{width: usize, height: usize},
+  Unsupported(&'static str),
+}
+
+impl IppError {
+  pub fn from_status(status: IppStatus) -> Option<IppError> {
+    if !status.is_err() {
+      return None;
+    }
+    Some(match status {
+      IppStatus::IppStsNotSupportedModeErr          => IppError::NotSupportedMode,
+      IppStatus::IppStsCpuNotSupportedErr           => IppError::CpuNotSupported,
+      IppStatus::IppStsInplaceModeNotSupportedErr   => IppError::InplaceModeNotSupported,
+      IppStatus::IppStsErr                          => IppError::Unspecified,
+      IppStatus::IppStsNoMemErr                     => IppError::NoMem,
+      IppStatus::IppStsBadArgErr                    => IppError::BadArg,
+      IppStatus::IppStsSizeErr                      => IppError::Size,
+      IppStatus::IppStsRangeErr                     => IppError::Range,
+      IppStatus::IppStsNullPtrErr                   => IppError::NullPtr,
+      IppStatus::IppStsMemAllocErr                  => IppError::MemAlloc,
+      IppStatus::IppStsDivByZeroErr                 => IppError::DivByZero,
+      IppStatus::IppStsOutOfRangeErr                => IppError::OutOfRange,
+      IppStatus::IppStsDataTypeErr                  => IppError::DataType,
+      IppStatus::IppStsStepErr                      => IppError::Step,
+      IppStatus::IppStsContextMatchErr              => IppError::ContextMatch,
+      IppStatus::IppStsMirrorFlipErr                => IppError::MirrorFlip,
+      IppStatus::IppStsInterpolationErr             => IppError::Interpolation,
+      IppStatus::IppStsResizeFactorErr              => IppError::ResizeFactor,
+      IppStatus::IppStsMaskSizeErr                  => IppError::MaskSize,
+      IppStatus::IppStsAnchorErr                    => IppError::Anchor,
+      IppStatus::IppStsChannelErr                   => IppError::Channel,
+      IppStatus::IppStsNumChannelsErr               => IppError::NumChannels,
+      IppStatus::IppStsNotEvenStepErr               => IppError::NotEvenStep,
+      IppStatus::IppStsBorderErr                    => IppError::Border,
+      IppStatus::IppStsMisalignedOffsetErr          => IppError::MisalignedOffset,
+      IppStatus::IppStsWarpDirectionErr             => IppError::WarpDirection,
+      IppStatus::IppStsExceededSizeErr              => IppError::ExceededSize,
+      IppStatus::IppStsWarpTransformTypeErr         => IppError::WarpTransformType,
+      status => IppError::UnknownStatus(status.code()),
+    })
+  }
+
+  /// The IPP status code this error was converted from, if any.
+  pub fn status(&self) -> Option<IppStatus> {
+    Some(match *self {
+      IppError::NotSupportedMode        => IppStatus::IppStsNotSupportedModeErr,
+      IppError::CpuNotSupported         => IppStatus::IppStsCpuNotSupportedErr,
+      IppError::InplaceModeNotSupported => IppStatus::IppStsInplaceModeNotSupportedErr,
+      IppError::Unspecified             => IppStatus::IppStsErr,
+      IppError::NoMem                   => IppStatus::IppStsNoMemErr,
+      IppError::BadArg                  => IppStatus::IppStsBadArgErr,
+      IppError::Size                    => IppStatus::IppStsSizeErr,
+      IppError::Range                   => IppStatus::IppStsRangeErr,
+      IppError::NullPtr                 => IppStatus::IppStsNullPtrErr,
+      IppError::MemAlloc                => IppStatus::IppStsMemAllocErr,
+      IppError::DivByZero               => IppStatus::IppStsDivByZeroErr,
+      IppError::OutOfRange              => IppStatus::IppStsOutOfRangeErr,
+      IppError::DataType                => IppStatus::IppStsDataTypeErr,
+      IppError::Step                    => IppStatus::IppStsStepErr,
+      IppError::ContextMatch            => IppStatus::IppStsContextMatchErr,
+      IppError::MirrorFlip              => IppStatus::IppStsMirrorFlipErr,
+      IppError::Interpolation           => IppStatus::IppStsInterpolationErr,
+      IppError::ResizeFactor            => IppStatus::IppStsResizeFactorErr,
+      IppError::MaskSize                => IppStatus::IppStsMaskSizeErr,
+      IppError::Anchor                  => IppStatus::IppStsAnchorErr,
+      IppError::Channel                 => IppStatus::IppStsChannelErr,
+      IppError::NumChannels             => IppStatus::IppStsNumChannelsErr,
+      IppError::NotEvenStep             => IppStatus::IppStsNotEvenStepErr,
+      IppError::Border                  => IppStatus::IppStsBorderErr,
+      IppError::MisalignedOffset        => IppStatus::IppStsMisalignedOffsetErr,
+      IppError::WarpDirection           => IppStatus::IppStsWarpDirectionErr,
+      IppError::ExceededSize            => IppStatus::IppStsExceededSizeErr,
+      IppError::WarpTransformType       => IppStatus::IppStsWarpTransformTypeErr,
+      IppError::UnknownStatus(code)     => IppStatus(code as _),
+      _ => return None,
+    })
+  }
+}
+
+impl fmt::Display for IppError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(status) = self.status() {
+      return write!(f, "{}", ipp_status_string(status));
+    }
+    match *self {
+      IppError::Alloc => write!(f, "ipp: allocation failed"),
+      IppError::BufferLen{expected, actual} => write!(f, "ipp: buffer length mismatch: expected {}, actual {}", expected, actual),
+      IppError::OutOfBounds => write!(f, "ipp: access out of bounds"),
+      IppError::InvalidArg(msg) => write!(f, "ipp: invalid argument: {}", msg),
+      IppError::InvalidSize{width, height} => write!(f, "ipp: invalid image size: {} x {}", width, height),
+      IppError::Unsupported(msg) => write!(f, "ipp: unsupported: {}", msg),
+      _ => unreachable!(),
+    }
+  }
+}
+
+impl Error for IppError {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IppWarning {
+  NoOperation,
+  MisalignedBuf,
+  SqrtNegArg,
+  InvZero,
+  EvenMedianMaskSize,
+  DivByZero,
+  LnZeroArg,
+  LnNegArg,
+  NanArg,
+  UnknownStatus(i32),
+}
+
+impl IppWarning {
+  pub fn from_status(status: IppStatus) -> Option<IppWarning> {
+    if !status.is_warning() {
+      return None;
+    }
+    Some(match status {
+      IppStatus::IppStsNoOperation          => IppWarning::NoOperation,
+      IppStatus::IppStsMisalignedBuf        => IppWarning::MisalignedBuf,
+      IppStatus::IppStsSqrtNegArg           => IppWarning::SqrtNegArg,
+      IppStatus::IppStsInvZero              => IppWarning::InvZero,
+      IppStatus::IppStsEvenMedianMaskSize   => IppWarning::EvenMedianMaskSize,
+      IppStatus::IppStsDivByZero            => IppWarning::DivByZero,
+      IppStatus::IppStsLnZeroArg            => IppWarning::LnZeroArg,
+      IppStatus::IppStsLnNegArg             => IppWarning::LnNegArg,
+      IppStatus::IppStsNanArg               => IppWarning::NanArg,
+      status => IppWarning::UnknownStatus(status.code()),
+    })
+  }
+
+  pub fn status(&self) -> IppStatus {
+    match *self {
+      IppWarning::NoOperation           => IppStatus::IppStsNoOperation,
+      IppWarning::MisalignedBuf         => IppStatus::IppStsMisalignedBuf,
+      IppWarning::SqrtNegArg            => IppStatus::IppStsSqrtNegArg,
+      IppWarning::InvZero               => IppStatus::IppStsInvZero,
+      IppWarning::EvenMedianMaskSize    => IppStatus::IppStsEvenMedianMaskSize,
+      IppWarning::DivByZero             => IppStatus::IppStsDivByZero,
+      IppWarning::LnZeroArg             => IppStatus::IppStsLnZeroArg,
+      IppWarning::LnNegArg              => IppStatus::IppStsLnNegArg,
+      IppWarning::NanArg                => IppStatus::IppStsNanArg,
+      IppWarning::UnknownStatus(code)   => IppStatus(code as _),
+    }
+  }
+}
+
+impl fmt::Display for IppWarning {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", ipp_status_string(self.status()))
+  }
+}
+
+/// Returns IPP's own description of a status code, e.g.
+/// "ippStsSizeErr: Incorrect value for data size".
+pub fn ipp_status_string(status: IppStatus) -> &'static str {
+  let ptr = unsafe { ippGetStatusString(status) };
+  if ptr.is_null() {
+    return "ipp: unknown status";
+  }
+  // IPP returns pointers into a static string table.
+  let s = unsafe { CStr::from_ptr(ptr) };
+  s.to_str().unwrap_or("ipp: unknown status")
+}
+
+thread_local!(static LAST_WARNING: Cell<Option<IppWarning>> = const { Cell::new(None) });
+
+/// Converts an IPP status into a `Result`. Warnings (positive status codes)
+/// do not abort the operation; they are passed back in the `Ok` variant and
+/// also recorded for `ipp_take_warning`.
+pub fn ipp_check(status: IppStatus) -> IppResult<Option<IppWarning>> {
+  match IppError::from_status(status) {
+    Some(e) => Err(e),
+    None => {
+      let warning = IppWarning::from_status(status);
+      if let Some(warning) = warning {
+        record_warning(warning);
+      }
+      Ok(warning)
+    }
+  }
+}
+
+/// Returns and clears the most recent warning IPP reported on this thread.
+/// The `try_*` functions succeed when IPP only warns, so call this after one
+/// of them to find out whether it did.
+pub fn ipp_take_warning() -> Option<IppWarning> {
+  LAST_WARNING.with(|last| last.take())
+}
+
+fn record_warning(warning: IppWarning) {
+  LAST_WARNING.with(|last| last.set(Some(warning)));
+}
+
+fn ipp_size(width: usize, height: usize) -> IppResult<IppiSize> {
+  if width == 0 || height == 0 || width > c_int::MAX as usize || height > c_int::MAX as usize {
+    return Err(IppError::InvalidSize{width: width, height: height});
+  }
+  Ok(IppiSize{width: width as _, height: height as _})
+}
 
 pub struct IppBuf<T> where T: Copy {
   ptr:  *mut T,
@@ -21,20 +291,38 @@ impl<T> Drop for IppBuf<T> where T: Copy {
   }
 }
 
+// `IppBuf` uniquely owns its allocation and only hands out a mutable pointer
+// through `&mut self`, so it is thread-safe in the same way as a `Box<[T]>`.
+unsafe impl<T> Send for IppBuf<T> where T: Copy + Send {}
+unsafe impl<T> Sync for IppBuf<T> where T: Copy + Sync {}
+
 impl IppBuf<u8> {
   pub fn alloc(len: usize) -> IppBuf<u8> {
+    IppBuf::<u8>::try_alloc(len).unwrap()
+  }
+
+  pub fn try_alloc(len: usize) -> IppResult<IppBuf<u8>> {
+    if len > c_int::MAX as usize {
+      return Err(IppError::Alloc);
+    }
     let ptr = unsafe { ippsMalloc_8u(len as _) };
-    assert!(!ptr.is_null());
-    IppBuf{
+    if ptr.is_null() {
+      return Err(IppError::Alloc);
+    }
+    Ok(IppBuf{
       ptr:  ptr,
       len:  len,
-    }
+    })
   }
 
   pub fn len(&self) -> usize {
     self.len
   }
 
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
   pub fn as_ptr(&self) -> *const u8 {
     self.ptr
   }
@@ -44,36 +332,135 @@ impl IppBuf<u8> {
   }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn ipp_copy2d_u8(
     width: usize, height: usize,
     src_offset_x: usize, src_offset_y: usize, src_pitch: usize, src: &[u8],
     dst_offset_x: usize, dst_offset_y: usize, dst_pitch: usize, dst: &mut [u8])
 {
-  assert!(src_offset_x <= src_pitch);
-  assert!(src_pitch * src_offset_y <= src.len());
-  assert!(width <= src_pitch);
-  assert!(dst_offset_x <= dst_pitch);
-  assert!(dst_pitch * dst_offset_y <= dst.len());
-  assert!(width <= dst_pitch);
-  let src_offset = src_offset_x + src_pitch * src_offset_y;
-  let dst_offset = dst_offset_x + dst_pitch * dst_offset_y;
-  // TODO(20170217): do more checking to ensure no out of bounds.
-  let status = unsafe { ippiCopy_8u_C1R(
-      src.as_ptr().offset(src_offset as isize),
-      src_pitch as _,
-      dst.as_mut_ptr().offset(dst_offset as isize),
-      dst_pitch as _,
-      IppiSize{width: width as _, height: height as _},
-  ) };
-  assert!(status.is_ok());
+  ipp_try_copy2d_u8(
+      width, height,
+      src_offset_x, src_offset_y, src_pitch, src,
+      dst_offset_x, dst_offset_y, dst_pitch, dst,
+  ).unwrap();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ipp_try_copy2d_u8(
+    width: usize, height: usize,
+    src_offset_x: usize, src_offset_y: usize, src_pitch: usize, src: &[u8],
+    dst_offset_x: usize, dst_offset_y: usize, dst_pitch: usize, dst: &mut [u8])
+-> IppResult<()>
+{
+  ipp_try_copy2d(
+      IppChannelLayout::C1,
+      width, height,
+      src_offset_x, src_offset_y, src_pitch, src,
+      dst_offset_x, dst_offset_y, dst_pitch, dst,
+  )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ipp_copy2d<T>(
+    layout: IppChannelLayout,
+    width: usize, height: usize,
+    src_offset_x: usize, src_offset_y: usize, src_pitch: usize, src: &[T],
+    dst_offset_x: usize, dst_offset_y: usize, dst_pitch: usize, dst: &mut [T])
+where T: IppPixel
+{
+  ipp_try_copy2d(
+      layout,
+      width, height,
+      src_offset_x, src_offset_y, src_pitch, src,
+      dst_offset_x, dst_offset_y, dst_pitch, dst,
+  ).unwrap();
+}
+
+/// Copies a `width` x `height` pixel rectangle between two pitched host
+/// buffers. Offsets are in pixels and pitches are in elements of `T`. Both
+/// rectangles are checked to lie entirely within their buffers, up to the
+/// end of the last row.
+#[allow(clippy::too_many_arguments)]
+pub fn ipp_try_copy2d<T>(
+    layout: IppChannelLayout,
+    width: usize, height: usize,
+    src_offset_x: usize, src_offset_y: usize, src_pitch: usize, src: &[T],
+    dst_offset_x: usize, dst_offset_y: usize, dst_pitch: usize, dst: &mut [T])
+-> IppResult<()>
+where T: IppPixel
+{
+  let src_extent = (src_offset_x.checked_add(width), src_offset_y.checked_add(height));
+  let dst_extent = (dst_offset_x.checked_add(width), dst_offset_y.checked_add(height));
+  let (src_width, src_height, dst_width, dst_height) = match (src_extent, dst_extent) {
+    ((Some(sw), Some(sh)), (Some(dw), Some(dh))) => (sw, sh, dw, dh),
+    _ => return Err(IppError::OutOfBounds),
+  };
+  let (src_step, dst_step) = match (src_pitch.checked_mul(size_of::<T>()), dst_pitch.checked_mul(size_of::<T>())) {
+    (Some(ss), Some(ds)) => (ss, ds),
+    _ => return Err(IppError::OutOfBounds),
+  };
+  let src = IppImageView::from_slice(src, src_width, src_height, src_step, layout)?;
+  let mut dst = IppImageViewMut::from_slice_mut(dst, dst_width, dst_height, dst_step, layout)?;
+  let src_roi = src.roi(src_offset_x, src_offset_y, width, height)?;
+  let mut dst_roi = dst.roi_mut(dst_offset_x, dst_offset_y, width, height)?;
+  ipp_try_copy_image(&src_roi, &mut dst_roi)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum IppChannelLayout {
+  C1,
+  C3,
+  C4,
+  // Four interleaved channels, the last of which is alpha; IPP operations on
+  // AC4 images leave the alpha channel of the destination untouched.
+  AC4,
+}
+
+impl IppChannelLayout {
+  pub fn num_channels(&self) -> usize {
+    match *self {
+      IppChannelLayout::C1  => 1,
+      IppChannelLayout::C3  => 3,
+      IppChannelLayout::C4  => 4,
+      IppChannelLayout::AC4 => 4,
+    }
+  }
 }
 
 pub trait IppImageBufExt<T> where T: Copy {
-  fn alloc(width: usize, height: usize) -> Self where Self: Sized;
-  fn write(&mut self, ext_buf: &[T]);
-  fn write_strided(&mut self, ext_width: usize, ext_height: usize, ext_buf: &[T]);
-  fn read(&self, ext_buf: &mut [T]);
-  fn read_strided(&self, ext_width: usize, ext_height: usize, ext_buf: &mut [T]);
+  fn try_alloc_with_layout(width: usize, height: usize, layout: IppChannelLayout) -> IppResult<Self> where Self: Sized;
+  fn try_write(&mut self, ext_buf: &[T]) -> IppResult<()>;
+  fn try_write_strided(&mut self, ext_width: usize, ext_height: usize, ext_buf: &[T]) -> IppResult<()>;
+  fn try_read(&self, ext_buf: &mut [T]) -> IppResult<()>;
+  fn try_read_strided(&self, ext_width: usize, ext_height: usize, ext_buf: &mut [T]) -> IppResult<()>;
+
+  fn try_alloc(width: usize, height: usize) -> IppResult<Self> where Self: Sized {
+    Self::try_alloc_with_layout(width, height, IppChannelLayout::C1)
+  }
+
+  fn alloc(width: usize, height: usize) -> Self where Self: Sized {
+    Self::try_alloc(width, height).unwrap()
+  }
+
+  fn alloc_with_layout(width: usize, height: usize, layout: IppChannelLayout) -> Self where Self: Sized {
+    Self::try_alloc_with_layout(width, height, layout).unwrap()
+  }
+
+  fn write(&mut self, ext_buf: &[T]) {
+    self.try_write(ext_buf).unwrap();
+  }
+
+  fn write_strided(&mut self, ext_width: usize, ext_height: usize, ext_buf: &[T]) {
+    self.try_write_strided(ext_width, ext_height, ext_buf).unwrap();
+  }
+
+  fn read(&self, ext_buf: &mut [T]) {
+    self.try_read(ext_buf).unwrap();
+  }
+
+  fn read_strided(&self, ext_width: usize, ext_height: usize, ext_buf: &mut [T]) {
+    self.try_read_strided(ext_width, ext_height, ext_buf).unwrap();
+  }
 }
 
 pub struct IppImageBuf<T> where T: Copy {
@@ -81,6 +468,7 @@ pub struct IppImageBuf<T> where T: Copy {
   width:    usize,
   height:   usize,
   pitch:    usize,
+  layout:   IppChannelLayout,
 }
 
 impl<T> Drop for IppImageBuf<T> where T: Copy {
@@ -90,472 +478,1121 @@ impl<T> Drop for IppImageBuf<T> where T: Copy {
   }
 }
 
-impl IppImageBufExt<u8> for IppImageBuf<u8> {
-  fn alloc(width: usize, height: usize) -> IppImageBuf<u8> {
-    let mut pitch: i32 = 0;
-    let ptr = unsafe { ippiMalloc_8u_C1(width as _, height as _, &mut pitch as *mut _) };
-    assert!(!ptr.is_null());
-    IppImageBuf{
-      ptr:      ptr,
-      width:    width,
-      height:   height,
-      pitch:    pitch as _,
-    }
+// Same reasoning as for `IppBuf`: the pixels are uniquely owned and are only
+// written through `&mut self` or a mutable view borrowed from it.
+unsafe impl<T> Send for IppImageBuf<T> where T: Copy + Send {}
+unsafe impl<T> Sync for IppImageBuf<T> where T: Copy + Sync {}
+
+impl<T> IppImageBuf<T> where T: Copy {
+  pub fn width(&self) -> usize {
+    self.width
   }
 
-  fn write(&mut self, ext_buf: &[u8]) {
-    assert_eq!(ext_buf.len(), self.width * self.height);
-    let status = unsafe { ippiCopy_8u_C1R(
-        ext_buf.as_ptr(),
-        self.width as _,
-        self.ptr,
-        self.pitch as _,
-        IppiSize{width: self.width as _, height: self.height as _},
-    ) };
-    assert!(status.is_ok());
-  }
-
-  fn write_strided(&mut self, ext_width: usize, ext_height: usize, ext_buf: &[u8]) {
-    assert!(ext_width <= self.width);
-    assert!(ext_height <= self.height);
-    assert!(ext_buf.len() <= self.width * self.height);
-    let status = unsafe { ippiCopy_8u_C1R(
-        ext_buf.as_ptr(),
-        ext_width as _,
-        self.ptr,
-        self.pitch as _,
-        IppiSize{width: ext_width as _, height: ext_height as _},
-    ) };
-    assert!(status.is_ok());
+  pub fn height(&self) -> usize {
+    self.height
   }
 
-  fn read(&self, ext_buf: &mut [u8]) {
-    assert_eq!(ext_buf.len(), self.width * self.height);
-    let status = unsafe { ippiCopy_8u_C1R(
-        self.ptr,
-        self.pitch as _,
-        ext_buf.as_mut_ptr(),
-        self.width as _,
-        IppiSize{width: self.width as _, height: self.height as _},
-    ) };
-    assert!(status.is_ok());
-  }
-
-  fn read_strided(&self, ext_width: usize, ext_height: usize, ext_buf: &mut [u8]) {
-    assert!(ext_width <= self.width);
-    assert!(ext_height <= self.height);
-    assert!(ext_buf.len() <= self.width * self.height);
-    let status = unsafe { ippiCopy_8u_C1R(
-        self.ptr,
-        self.pitch as _,
-        ext_buf.as_mut_ptr(),
-        ext_width as _,
-        IppiSize{width: ext_width as _, height: ext_height as _},
-    ) };
-    assert!(status.is_ok());
+  pub fn layout(&self) -> IppChannelLayout {
+    self.layout
+  }
+
+  // Length in elements of a tightly packed, interleaved host image.
+  fn packed_len(&self) -> usize {
+    self.width * self.height * self.layout.num_channels()
+  }
+
+  // Step in bytes of a tightly packed, interleaved host row of `width` pixels.
+  fn packed_step(&self, width: usize) -> usize {
+    width * self.layout.num_channels() * size_of::<T>()
   }
 }
 
-impl IppImageBufExt<f32> for IppImageBuf<f32> {
-  fn alloc(width: usize, height: usize) -> IppImageBuf<f32> {
+impl<T> IppImageBufExt<T> for IppImageBuf<T> where T: IppPixel {
+  fn try_alloc_with_layout(width: usize, height: usize, layout: IppChannelLayout) -> IppResult<IppImageBuf<T>> {
+    let size = ipp_size(width, height)?;
+    if !T::supports_layout(layout) {
+      return Err(IppError::Unsupported("image buf: channel layout is not supported for this pixel type"));
+    }
     let mut pitch: i32 = 0;
-    let ptr = unsafe { ippiMalloc_32f_C1(width as _, height as _, &mut pitch as *mut _) };
-    assert!(!ptr.is_null());
-    IppImageBuf{
+    let ptr = unsafe { T::ipp_malloc(layout, size.width, size.height, &mut pitch as *mut _) };
+    if ptr.is_null() {
+      return Err(IppError::Alloc);
+    }
+    Ok(IppImageBuf{
       ptr:      ptr,
       width:    width,
       height:   height,
       pitch:    pitch as _,
+      layout:   layout,
+    })
+  }
+
+  fn try_write(&mut self, ext_buf: &[T]) -> IppResult<()> {
+    if ext_buf.len() != self.packed_len() {
+      return Err(IppError::BufferLen{expected: self.packed_len(), actual: ext_buf.len()});
     }
+    let (width, height) = (self.width, self.height);
+    self.try_write_strided(width, height, ext_buf)
   }
 
-  fn write(&mut self, ext_buf: &[f32]) {
-    assert_eq!(ext_buf.len(), self.width * self.height);
-    let status = unsafe { ippiCopy_32f_C1R(
-        ext_buf.as_ptr(),
-        self.width as _,
-        self.ptr,
-        self.pitch as _,
-        IppiSize{width: self.width as _, height: self.height as _},
-    ) };
-    assert!(status.is_ok());
-  }
-
-  fn write_strided(&mut self, ext_width: usize, ext_height: usize, ext_buf: &[f32]) {
-    assert!(ext_width <= self.width);
-    assert!(ext_height <= self.height);
-    assert!(ext_buf.len() <= self.width * self.height);
-    let status = unsafe { ippiCopy_32f_C1R(
-        ext_buf.as_ptr(),
-        ext_width as _,
-        self.ptr,
-        self.pitch as _,
-        IppiSize{width: ext_width as _, height: ext_height as _},
-    ) };
-    assert!(status.is_ok());
+  fn try_write_strided(&mut self, ext_width: usize, ext_height: usize, ext_buf: &[T]) -> IppResult<()> {
+    let src = IppImageView::from_slice(ext_buf, ext_width, ext_height, self.packed_step(ext_width), self.layout)?;
+    let mut dst = self.roi_mut(0, 0, ext_width, ext_height)?;
+    ipp_try_copy_image(&src, &mut dst)
   }
 
-  fn read(&self, ext_buf: &mut [f32]) {
-    assert_eq!(ext_buf.len(), self.width * self.height);
-    let status = unsafe { ippiCopy_32f_C1R(
-        self.ptr,
-        self.pitch as _,
-        ext_buf.as_mut_ptr(),
-        self.width as _,
-        IppiSize{width: self.width as _, height: self.height as _},
-    ) };
-    assert!(status.is_ok());
-  }
-
-  fn read_strided(&self, ext_width: usize, ext_height: usize, ext_buf: &mut [f32]) {
-    assert!(ext_width <= self.width);
-    assert!(ext_height <= self.height);
-    assert!(ext_buf.len() <= self.width * self.height);
-    let status = unsafe { ippiCopy_32f_C1R(
-        self.ptr,
-        self.pitch as _,
-        ext_buf.as_mut_ptr(),
-        ext_width as _,
-        IppiSize{width: ext_width as _, height: ext_height as _},
-    ) };
-    assert!(status.is_ok());
+  fn try_read(&self, ext_buf: &mut [T]) -> IppResult<()> {
+    if ext_buf.len() != self.packed_len() {
+      return Err(IppError::BufferLen{expected: self.packed_len(), actual: ext_buf.len()});
+    }
+    self.try_read_strided(self.width, self.height, ext_buf)
+  }
+
+  fn try_read_strided(&self, ext_width: usize, ext_height: usize, ext_buf: &mut [T]) -> IppResult<()> {
+    let src = self.roi(0, 0, ext_width, ext_height)?;
+    let mut dst = IppImageViewMut::from_slice_mut(ext_buf, ext_width, ext_height, self.packed_step(ext_width), self.layout)?;
+    ipp_try_copy_image(&src, &mut dst)
   }
 }
 
 #[derive(Clone, Copy)]
 pub enum IppImageResizeKind {
+  /// Nearest-neighbour sampling; never blends pixel values, so it is suited
+  /// to label images. Ignores the border mode.
+  Nearest,
   Linear,
   Cubic{b: f32, c: f32},
   Lanczos{nlobes: usize},
+  /// Area-averaging super sampling; downscaling only. Ignores the border
+  /// mode.
+  Super,
+}
+
+/// How resize synthesizes source pixels beyond the edges of the source ROI.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IppBorderMode<T> where T: Copy {
+  /// Replicate the edge pixels of the source ROI.
+  Repl,
+  /// Use a constant pixel; only the first `num_channels` values are used.
+  Const([T; 4]),
+}
+
+/// Sides of the source ROI beyond which valid pixels exist in memory, and
+/// which resize should read instead of applying the border mode.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct IppBorderInMem {
+  pub top:      bool,
+  pub bottom:   bool,
+  pub left:     bool,
+  pub right:    bool,
+}
+
+impl IppBorderInMem {
+  pub fn none() -> IppBorderInMem {
+    IppBorderInMem::default()
+  }
+
+  pub fn all() -> IppBorderInMem {
+    IppBorderInMem{top: true, bottom: true, left: true, right: true}
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct IppResizeBorder<T> where T: Copy {
+  pub mode:     IppBorderMode<T>,
+  pub in_mem:   IppBorderInMem,
+}
+
+impl<T> Default for IppResizeBorder<T> where T: Copy {
+  fn default() -> IppResizeBorder<T> {
+    IppResizeBorder::repl()
+  }
+}
+
+impl<T> IppResizeBorder<T> where T: Copy {
+  pub fn repl() -> IppResizeBorder<T> {
+    IppResizeBorder{
+      mode:     IppBorderMode::Repl,
+      in_mem:   IppBorderInMem::none(),
+    }
+  }
+
+  pub fn constant(value: [T; 4]) -> IppResizeBorder<T> {
+    IppResizeBorder{
+      mode:     IppBorderMode::Const(value),
+      in_mem:   IppBorderInMem::none(),
+    }
+  }
+
+  pub fn with_in_mem(self, in_mem: IppBorderInMem) -> IppResizeBorder<T> {
+    IppResizeBorder{
+      mode:     self.mode,
+      in_mem:   in_mem,
+    }
+  }
+
+  fn border_type(&self) -> IppiBorderType {
+    let mut border = match self.mode {
+      IppBorderMode::Repl     => IppiBorderType::ippBorderRepl,
+      IppBorderMode::Const(_) => IppiBorderType::ippBorderConst,
+    };
+    if self.in_mem.top {
+      border = border | IppiBorderType::ippBorderInMemTop;
+    }
+    if self.in_mem.bottom {
+      border = border | IppiBorderType::ippBorderInMemBottom;
+    }
+    if self.in_mem.left {
+      border = border | IppiBorderType::ippBorderInMemLeft;
+    }
+    if self.in_mem.right {
+      border = border | IppiBorderType::ippBorderInMemRight;
+    }
+    border
+  }
+
+  fn border_value(&self) -> *const T {
+    match self.mode {
+      IppBorderMode::Repl           => null(),
+      IppBorderMode::Const(ref val) => val.as_ptr(),
+    }
+  }
 }
 
 pub trait IppImageResizeExt<T> where T: Copy {
-  fn create(kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Result<Self, ()> where Self: Sized;
-  fn resize(&mut self, src: &IppImageBuf<T>, dst: &mut IppImageBuf<T>);
+  fn create_with_layout(kind: IppImageResizeKind, layout: IppChannelLayout, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Self> where Self: Sized;
+  fn try_resize_view(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()>;
+
+  fn create(kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Self> where Self: Sized {
+    Self::create_with_layout(kind, IppChannelLayout::C1, src_width, src_height, dst_width, dst_height)
+  }
+
+  fn try_resize(&mut self, src: &IppImageBuf<T>, dst: &mut IppImageBuf<T>) -> IppResult<()> {
+    self.try_resize_view(&src.view(), &mut dst.view_mut())
+  }
+
+  fn resize(&mut self, src: &IppImageBuf<T>, dst: &mut IppImageBuf<T>) {
+    self.try_resize(src, dst).unwrap();
+  }
+
+  fn resize_view(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_resize_view(src, dst).unwrap();
+  }
 }
 
-pub struct IppImageResize<T> where T: Copy {
+/// An initialized resize plan. The spec is only written during `create`;
+/// afterwards IPP reads it through a const pointer and keeps all per-call
+/// state in a separate `IppResizeWorkBuf`, so a single spec can drive any
+/// number of concurrent resizes, each with its own work buffer.
+pub struct IppResizeSpec<T> where T: Copy {
   spec: IppBuf<u8>,
-  //bord: IppBuf<u8>,
-  buf:  IppBuf<u8>,
+  bord: IppiBorderSize,
+  buf_size: usize,
   kind: IppImageResizeKind,
+  aa:   bool,
+  lay:  IppChannelLayout,
   src:  (usize, usize),
   dst:  (usize, usize),
   _mrk: PhantomData<fn (T)>,
 }
 
-impl IppImageResizeExt<u8> for IppImageResize<u8> {
-  fn create(kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Result<Self, ()> {
+/// Scratch memory for `IppResizeSpec::resize`.
+pub struct IppResizeWorkBuf {
+  buf:  IppBuf<u8>,
+}
+
+impl IppResizeWorkBuf {
+  pub fn alloc(size: usize) -> IppResizeWorkBuf {
+    IppResizeWorkBuf::try_alloc(size).unwrap()
+  }
+
+  pub fn try_alloc(size: usize) -> IppResult<IppResizeWorkBuf> {
+    Ok(IppResizeWorkBuf{
+      buf:  IppBuf::<u8>::try_alloc(size)?,
+    })
+  }
+
+  pub fn len(&self) -> usize {
+    self.buf.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.buf.is_empty()
+  }
+}
+
+impl<T> IppResizeSpec<T> where T: IppPixel {
+  pub fn create(kind: IppImageResizeKind, layout: IppChannelLayout, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<IppResizeSpec<T>> {
+    IppResizeSpec::create_with_antialiasing(kind, layout, false, src_width, src_height, dst_width, dst_height)
+  }
+
+  /// Like `create`, but with `antialiasing` the plan filters the source
+  /// before sampling so that large downscales do not alias. Antialiasing is
+  /// only available for `u8` and `f32` pixels.
+  pub fn create_with_antialiasing(kind: IppImageResizeKind, layout: IppChannelLayout, antialiasing: bool, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<IppResizeSpec<T>> {
+    let src_size = ipp_size(src_width, src_height)?;
+    let dst_size = ipp_size(dst_width, dst_height)?;
+    if layout == IppChannelLayout::AC4 {
+      return Err(IppError::Unsupported("resize: AC4 layout is not supported, use C4"));
+    }
+    if !T::supports_resize(kind) {
+      return Err(IppError::Unsupported("resize: interpolation kind is not supported for this pixel type"));
+    }
+    if let IppImageResizeKind::Lanczos{nlobes} = kind {
+      if nlobes != 2 && nlobes != 3 {
+        return Err(IppError::Unsupported("resize: Lanczos filter supports only 2 or 3 lobes"));
+      }
+    }
+    if let IppImageResizeKind::Super = kind {
+      if dst_width > src_width || dst_height > src_height {
+        return Err(IppError::Unsupported("resize: super sampling supports only downscaling"));
+      }
+    }
+    if antialiasing && !T::supports_antialiasing() {
+      return Err(IppError::Unsupported("resize: antialiasing is not supported for this pixel type"));
+    }
+    if antialiasing {
+      match kind {
+        IppImageResizeKind::Nearest | IppImageResizeKind::Super => {
+          return Err(IppError::Unsupported("resize: antialiasing requires linear, cubic or Lanczos interpolation"));
+        }
+        _ => {}
+      }
+    }
     let interp_ty = match kind {
+      IppImageResizeKind::Nearest       => IppiInterpolationType::ippNearest,
       IppImageResizeKind::Linear        => IppiInterpolationType::ippLinear,
       IppImageResizeKind::Cubic{..}     => IppiInterpolationType::ippCubic,
       IppImageResizeKind::Lanczos{..}   => IppiInterpolationType::ippLanczos,
+      IppImageResizeKind::Super         => IppiInterpolationType::ippSuper,
     };
     let mut spec_size = 0;
     let mut init_buf_size = 0;
-    let status = unsafe { ippiResizeGetSize_8u(
-        IppiSize{width: src_width as _, height: src_height as _},
-        IppiSize{width: dst_width as _, height: dst_height as _},
+    let status = unsafe { T::ipp_resize_get_size(
+        src_size,
+        dst_size,
         interp_ty,
-        0, // antialiasing.
+        antialiasing as u32,
         &mut spec_size as *mut _,
         &mut init_buf_size as *mut _,
     ) };
-    assert!(status.is_ok());
-    let mut spec = IppBuf::<u8>::alloc(spec_size as _);
-    match kind {
-      IppImageResizeKind::Linear => {
-        let status = unsafe { ippiResizeLinearInit_8u(
-            IppiSize{width: src_width as _, height: src_height as _},
-            IppiSize{width: dst_width as _, height: dst_height as _},
+    ipp_check(status)?;
+    let mut spec = IppBuf::<u8>::try_alloc(spec_size as _)?;
+    if antialiasing {
+      let mut init_buf = IppBuf::<u8>::try_alloc(init_buf_size as _)?;
+      let status = unsafe { match kind {
+        IppImageResizeKind::Linear => ippiResizeAntialiasingLinearInit(
+            src_size,
+            dst_size,
             spec.as_mut_ptr() as *mut _,
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Cubic{b, c} => {
-        let mut init_buf = IppBuf::<u8>::alloc(init_buf_size as _);
-        let status = unsafe { ippiResizeCubicInit_8u(
-            IppiSize{width: src_width as _, height: src_height as _},
-            IppiSize{width: dst_width as _, height: dst_height as _},
+            init_buf.as_mut_ptr(),
+        ),
+        IppImageResizeKind::Cubic{b, c} => ippiResizeAntialiasingCubicInit(
+            src_size,
+            dst_size,
             b, c,
             spec.as_mut_ptr() as *mut _,
             init_buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Lanczos{nlobes} => {
-        let mut init_buf = IppBuf::<u8>::alloc(init_buf_size as _);
-        let status = unsafe { ippiResizeLanczosInit_8u(
-            IppiSize{width: src_width as _, height: src_height as _},
-            IppiSize{width: dst_width as _, height: dst_height as _},
+        ),
+        IppImageResizeKind::Lanczos{nlobes} => ippiResizeAntialiasingLanczosInit(
+            src_size,
+            dst_size,
             nlobes as _,
             spec.as_mut_ptr() as *mut _,
             init_buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
+        ),
+        IppImageResizeKind::Nearest | IppImageResizeKind::Super => unreachable!(),
+      } };
+      ipp_check(status)?;
+    } else {
+      match kind {
+        IppImageResizeKind::Nearest => {
+          let status = unsafe { T::ipp_resize_nearest_init(
+              src_size,
+              dst_size,
+              spec.as_mut_ptr() as *mut _,
+          ) };
+          ipp_check(status)?;
+        }
+        IppImageResizeKind::Linear => {
+          let status = unsafe { T::ipp_resize_linear_init(
+              src_size,
+              dst_size,
+              spec.as_mut_ptr() as *mut _,
+          ) };
+          ipp_check(status)?;
+        }
+        IppImageResizeKind::Cubic{b, c} => {
+          let mut init_buf = IppBuf::<u8>::try_alloc(init_buf_size as _)?;
+          let status = unsafe { T::ipp_resize_cubic_init(
+              src_size,
+              dst_size,
+              b, c,
+              spec.as_mut_ptr() as *mut _,
+              init_buf.as_mut_ptr(),
+          ) };
+          ipp_check(status)?;
+        }
+        IppImageResizeKind::Lanczos{nlobes} => {
+          let mut init_buf = IppBuf::<u8>::try_alloc(init_buf_size as _)?;
+          let status = unsafe { T::ipp_resize_lanczos_init(
+              src_size,
+              dst_size,
+              nlobes as _,
+              spec.as_mut_ptr() as *mut _,
+              init_buf.as_mut_ptr(),
+          ) };
+          ipp_check(status)?;
+        }
+        IppImageResizeKind::Super => {
+          let status = unsafe { T::ipp_resize_super_init(
+              src_size,
+              dst_size,
+              spec.as_mut_ptr() as *mut _,
+          ) };
+          ipp_check(status)?;
+        }
       }
     }
-    /*let mut border_size = IppiBorderSize::default();
-    let status = unsafe { ippiResizeGetBorderSize_8u(
+    let mut bord = IppiBorderSize::default();
+    let status = unsafe { T::ipp_resize_get_border_size(
         spec.as_ptr() as *const IppiResizeSpec_32f,
-        &mut border_size as *mut _,
+        &mut bord as *mut _,
     ) };
-    assert!(status.is_ok());
-    let border_circum = border_size.border_left + border_size.border_top + border_size.border_right + border_size.border_bottom;
-    let bord = IppBuf::<u8>::alloc(border_circum as _);*/
+    ipp_check(status)?;
     let mut buf_size = 0;
-    let status = unsafe { ippiResizeGetBufferSize_8u(
+    let status = unsafe { T::ipp_resize_get_buffer_size(
         spec.as_ptr() as *const IppiResizeSpec_32f,
-        IppiSize{width: dst_width as _, height: dst_height as _},
-        1, // num channels.
+        dst_size,
+        layout.num_channels() as _,
         &mut buf_size as *mut _,
     ) };
-    assert!(status.is_ok());
-    let buf = IppBuf::<u8>::alloc(buf_size as _);
-    Ok(IppImageResize{
+    ipp_check(status)?;
+    Ok(IppResizeSpec{
       spec: spec,
-      //bord: bord,
-      buf:  buf,
+      bord: bord,
+      buf_size: buf_size as _,
       kind: kind,
+      aa:   antialiasing,
+      lay:  layout,
       src:  (src_width, src_height),
       dst:  (dst_width, dst_height),
       _mrk: PhantomData,
     })
   }
 
-  fn resize(&mut self, src: &IppImageBuf<u8>, dst: &mut IppImageBuf<u8>) {
-    assert!(self.src.0 <= src.width);
-    assert!(self.src.1 <= src.height);
-    assert!(self.dst.0 <= dst.width);
-    assert!(self.dst.1 <= dst.height);
-    /*println!("DEBUG: ipp: resize: {} x {} ({}) -> {} x {} ({})",
-        src.width, src.height, src.pitch, dst.width, dst.height, dst.pitch);*/
-    match self.kind {
-      IppImageResizeKind::Linear        => {
-        let status = unsafe { ippiResizeLinear_8u_C1R(
-            src.ptr,
-            src.pitch as _,
-            dst.ptr,
-            dst.pitch as _,
-            IppiPoint{x: 0, y: 0},
-            IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
-            IppiBorderType::ippBorderRepl,
-            null(),
-            self.spec.as_ptr() as *const IppiResizeSpec_32f,
-            self.buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Cubic{..}     => {
-        let status = unsafe { ippiResizeCubic_8u_C1R(
-            src.ptr,
-            src.pitch as _,
-            dst.ptr,
-            dst.pitch as _,
-            IppiPoint{x: 0, y: 0},
-            IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
-            IppiBorderType::ippBorderRepl,
-            null(),
-            self.spec.as_ptr() as *const IppiResizeSpec_32f,
-            self.buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Lanczos{..}   => {
-        let status = unsafe { ippiResizeLanczos_8u_C1R(
-            src.ptr,
-            src.pitch as _,
-            dst.ptr,
-            dst.pitch as _,
-            IppiPoint{x: 0, y: 0},
-            IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
-            IppiBorderType::ippBorderRepl,
-            null(),
-            self.spec.as_ptr() as *const IppiResizeSpec_32f,
-            self.buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
+  pub fn work_buf_size(&self) -> usize {
+    self.buf_size
+  }
+
+  pub fn alloc_work_buf(&self) -> IppResult<IppResizeWorkBuf> {
+    IppResizeWorkBuf::try_alloc(self.buf_size)
+  }
+
+  pub fn resize(&self, work: &mut IppResizeWorkBuf, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_resize(work, src, dst).unwrap();
+  }
+
+  pub fn try_resize(&self, work: &mut IppResizeWorkBuf, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    self.try_resize_with_border(work, src, 0, 0, dst, IppResizeBorder::repl())
+  }
+
+  pub fn resize_with_border(&self, work: &mut IppResizeWorkBuf, src: &IppImageView<T>, src_x: usize, src_y: usize, dst: &mut IppImageViewMut<T>, border: IppResizeBorder<T>) {
+    self.try_resize_with_border(work, src, src_x, src_y, dst, border).unwrap();
+  }
+
+  /// Resizes the source ROI at (`src_x`, `src_y`) within `src`, whose size is
+  /// the plan's source size. Sides marked in `border.in_mem` read pixels of
+  /// `src` outside the ROI; `src` must extend at least `border_size()`
+  /// pixels past the ROI on each of those sides.
+  pub fn try_resize_with_border(&self, work: &mut IppResizeWorkBuf, src: &IppImageView<T>, src_x: usize, src_y: usize, dst: &mut IppImageViewMut<T>, border: IppResizeBorder<T>) -> IppResult<()> {
+    let src = self.check_views(src, src_x, src_y, dst, &border)?;
+    self.try_resize_band(work, &src, dst, 0, &border)
+  }
+
+  /// Size of the work buffer needed to resize a band of `band_height`
+  /// destination rows.
+  pub fn band_work_buf_size(&self, band_height: usize) -> IppResult<usize> {
+    let band_size = ipp_size(self.dst.0, band_height)?;
+    let mut buf_size = 0;
+    let status = unsafe { T::ipp_resize_get_buffer_size(
+        self.spec.as_ptr() as *const IppiResizeSpec_32f,
+        band_size,
+        self.lay.num_channels() as _,
+        &mut buf_size as *mut _,
+    ) };
+    ipp_check(status)?;
+    Ok(buf_size as _)
+  }
+
+  /// Allocates one work buffer per band for `try_resize_parallel`.
+  pub fn alloc_band_work_bufs(&self, num_bands: usize) -> IppResult<Vec<IppResizeWorkBuf>> {
+    let num_bands = max(1, min(num_bands, self.dst.1));
+    let band_height = self.dst.1.div_ceil(num_bands);
+    let buf_size = self.band_work_buf_size(band_height)?;
+    let mut works = Vec::with_capacity(num_bands);
+    for _ in 0 .. num_bands {
+      works.push(IppResizeWorkBuf::try_alloc(buf_size)?);
+    }
+    Ok(works)
+  }
+
+  pub fn resize_parallel(&self, works: &mut [IppResizeWorkBuf], src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_resize_parallel(works, src, dst).unwrap();
+  }
+
+  /// Splits the destination into one band of rows per work buffer and
+  /// resizes the bands on separate threads. The output is identical to that
+  /// of `try_resize`. Each call spawns one scoped thread per band and joins
+  /// them before returning, so the spawn cost is only worth paying for
+  /// large destinations.
+  pub fn try_resize_parallel(&self, works: &mut [IppResizeWorkBuf], src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    self.try_resize_parallel_with_border(works, src, 0, 0, dst, IppResizeBorder::repl())
+  }
+
+  pub fn resize_parallel_with_border(&self, works: &mut [IppResizeWorkBuf], src: &IppImageView<T>, src_x: usize, src_y: usize, dst: &mut IppImageViewMut<T>, border: IppResizeBorder<T>) {
+    self.try_resize_parallel_with_border(works, src, src_x, src_y, dst, border).unwrap();
+  }
+
+  /// Parallel version of `try_resize_with_border`.
+  pub fn try_resize_parallel_with_border(&self, works: &mut [IppResizeWorkBuf], src: &IppImageView<T>, src_x: usize, src_y: usize, dst: &mut IppImageViewMut<T>, border: IppResizeBorder<T>) -> IppResult<()> {
+    let src = self.check_views(src, src_x, src_y, dst, &border)?;
+    if works.is_empty() {
+      return Err(IppError::InvalidArg("resize: parallel resize needs at least one work buffer"));
+    }
+    let (dst_width, dst_height) = self.dst;
+    let num_bands = min(works.len(), dst_height);
+    let band_height = dst_height.div_ceil(num_bands);
+    let mut bands = Vec::with_capacity(num_bands);
+    let mut rest = dst.roi_mut(0, 0, dst_width, dst_height)?;
+    let mut row = 0;
+    while row < dst_height {
+      let height = min(band_height, dst_height - row);
+      let (band, next) = rest.split_at_row_mut(height)?;
+      bands.push((row, band));
+      rest = next;
+      row += height;
+    }
+    thread::scope(|scope| {
+      let handles: Vec<_> = bands.into_iter().zip(works.iter_mut()).map(|((row, mut band), work)| {
+        let src = &src;
+        let border = &border;
+        scope.spawn(move || {
+          let band_result = self.try_resize_band(work, src, &mut band, row, border);
+          (band_result, ipp_take_warning())
+        })
+      }).collect();
+      // Warnings are recorded on the worker threads; pass them on to the
+      // caller's thread.
+      let mut result = Ok(());
+      for handle in handles {
+        let (band_result, warning) = handle.join().unwrap();
+        if let Some(warning) = warning {
+          record_warning(warning);
+        }
+        if result.is_ok() {
+          result = band_result;
+        }
       }
+      result
+    })
+  }
+
+  // Validates the views and the in-memory border sides, and returns the
+  // source ROI.
+  fn check_views<'a>(&self, src: &IppImageView<'a, T>, src_x: usize, src_y: usize, dst: &IppImageViewMut<T>, border: &IppResizeBorder<T>) -> IppResult<IppImageView<'a, T>> {
+    let src_roi = src.roi(src_x, src_y, self.src.0, self.src.1)?;
+    if !(self.dst.0 <= dst.width() && self.dst.1 <= dst.height()) {
+      return Err(IppError::OutOfBounds);
+    }
+    if src.layout() != self.lay || dst.layout() != self.lay {
+      return Err(IppError::InvalidArg("resize: image layout does not match resize layout"));
+    }
+    let in_mem = border.in_mem;
+    let right = src.width() - (src_x + self.src.0);
+    let bottom = src.height() - (src_y + self.src.1);
+    if (in_mem.top && src_y < self.bord.border_top as usize)
+        || (in_mem.bottom && bottom < self.bord.border_bottom as usize)
+        || (in_mem.left && src_x < self.bord.border_left as usize)
+        || (in_mem.right && right < self.bord.border_right as usize)
+    {
+      return Err(IppError::InvalidArg("resize: source is too small for the in-memory border"));
     }
+    Ok(src_roi)
   }
-}
 
-impl IppImageResizeExt<f32> for IppImageResize<f32> {
-  fn create(kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Result<Self, ()> {
-    let interp_ty = match kind {
-      IppImageResizeKind::Linear        => IppiInterpolationType::ippLinear,
-      IppImageResizeKind::Cubic{..}     => IppiInterpolationType::ippCubic,
-      IppImageResizeKind::Lanczos{..}   => IppiInterpolationType::ippLanczos,
+  // Resizes destination rows `row .. row + band.height()` (clipped to the
+  // plan's destination size) into `band`, whose first row is destination row
+  // `row`. IPP locates the matching source rows; edges between bands are
+  // marked as in memory so that IPP reads the neighbouring source rows
+  // instead of applying the border mode, and bands computed independently
+  // agree with a single full-size resize.
+  fn try_resize_band(&self, work: &mut IppResizeWorkBuf, src: &IppImageView<T>, band: &mut IppImageViewMut<T>, row: usize, border: &IppResizeBorder<T>) -> IppResult<()> {
+    let band_height = min(band.height(), self.dst.1 - row);
+    let mut border = *border;
+    if row > 0 {
+      border.in_mem.top = true;
+    }
+    if row + band_height < self.dst.1 {
+      border.in_mem.bottom = true;
+    }
+    let buf_size = if band_height == self.dst.1 {
+      self.buf_size
+    } else {
+      self.band_work_buf_size(band_height)?
     };
-    let mut spec_size = 0;
-    let mut init_buf_size = 0;
-    let status = unsafe { ippiResizeGetSize_32f(
-        IppiSize{width: src_width as _, height: src_height as _},
-        IppiSize{width: dst_width as _, height: dst_height as _},
-        interp_ty,
-        0, // antialiasing.
-        &mut spec_size as *mut _,
-        &mut init_buf_size as *mut _,
-    ) };
-    assert!(status.is_ok());
-    let mut spec = IppBuf::<u8>::alloc(spec_size as _);
-    match kind {
-      IppImageResizeKind::Linear => {
-        let status = unsafe { ippiResizeLinearInit_32f(
-            IppiSize{width: src_width as _, height: src_height as _},
-            IppiSize{width: dst_width as _, height: dst_height as _},
-            spec.as_mut_ptr() as *mut _,
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Cubic{b, c} => {
-        let mut init_buf = IppBuf::<u8>::alloc(init_buf_size as _);
-        let status = unsafe { ippiResizeCubicInit_32f(
-            IppiSize{width: src_width as _, height: src_height as _},
-            IppiSize{width: dst_width as _, height: dst_height as _},
-            b, c,
-            spec.as_mut_ptr() as *mut _,
-            init_buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Lanczos{nlobes} => {
-        let mut init_buf = IppBuf::<u8>::alloc(init_buf_size as _);
-        let status = unsafe { ippiResizeLanczosInit_32f(
-            IppiSize{width: src_width as _, height: src_height as _},
-            IppiSize{width: dst_width as _, height: dst_height as _},
-            nlobes as _,
-            spec.as_mut_ptr() as *mut _,
-            init_buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
+    if work.len() < buf_size {
+      return Err(IppError::BufferLen{expected: buf_size, actual: work.len()});
     }
-    let mut buf_size = 0;
-    let status = unsafe { ippiResizeGetBufferSize_32f(
-        spec.as_ptr() as *const IppiResizeSpec_32f,
-        IppiSize{width: dst_width as _, height: dst_height as _},
-        1, // num channels.
-        &mut buf_size as *mut _,
+    let dst_offset = IppiPoint{x: 0, y: row as _};
+    let mut src_offset = IppiPoint{x: 0, y: 0};
+    let status = unsafe { T::ipp_resize_get_src_offset(
+        self.spec.as_ptr() as *const IppiResizeSpec_32f,
+        dst_offset,
+        &mut src_offset as *mut _,
     ) };
-    assert!(status.is_ok());
-    let buf = IppBuf::<u8>::alloc(buf_size as _);
+    ipp_check(status)?;
+    let src_byte_offset = src_offset.y as usize * src.pitch() + src_offset.x as usize * self.lay.num_channels() * size_of::<T>();
+    /*println!("DEBUG: ipp: resize: {} x {} ({}) -> {} x {} ({})",
+        src.width(), src.height(), src.pitch(), band.width(), band.height(), band.pitch());*/
+    let src_ptr = unsafe { (src.as_ptr() as *const u8).add(src_byte_offset) as *const T };
+    let band_size = IppiSize{width: self.dst.0 as _, height: band_height as _};
+    let status = if self.aa {
+      unsafe { T::ipp_resize_antialiasing(
+          self.lay,
+          src_ptr,
+          src.pitch() as _,
+          band.as_mut_ptr(),
+          band.pitch() as _,
+          dst_offset,
+          band_size,
+          border.border_type(),
+          border.border_value(),
+          self.spec.as_ptr() as *const IppiResizeSpec_32f,
+          work.buf.as_mut_ptr(),
+      ) }
+    } else {
+      unsafe { T::ipp_resize(
+          self.kind,
+          self.lay,
+          src_ptr,
+          src.pitch() as _,
+          band.as_mut_ptr(),
+          band.pitch() as _,
+          dst_offset,
+          band_size,
+          border.border_type(),
+          border.border_value(),
+          self.spec.as_ptr() as *const IppiResizeSpec_32f,
+          work.buf.as_mut_ptr(),
+      ) }
+    };
+    ipp_check(status)?;
+    Ok(())
+  }
+}
+
+impl<T> IppResizeSpec<T> where T: Copy {
+  pub fn kind(&self) -> IppImageResizeKind {
+    self.kind
+  }
+
+  pub fn antialiasing(&self) -> bool {
+    self.aa
+  }
+
+  pub fn layout(&self) -> IppChannelLayout {
+    self.lay
+  }
+
+  pub fn src_size(&self) -> (usize, usize) {
+    self.src
+  }
+
+  pub fn dst_size(&self) -> (usize, usize) {
+    self.dst
+  }
+
+  /// Number of source pixels beyond each edge of the source ROI that the
+  /// interpolation kernel reads.
+  pub fn border_size(&self) -> IppiBorderSize {
+    self.bord
+  }
+}
+
+/// A resize spec bundled with its own work buffer.
+pub struct IppImageResize<T> where T: Copy {
+  spec: Arc<IppResizeSpec<T>>,
+  work: IppResizeWorkBuf,
+  bands:  Vec<IppResizeWorkBuf>,
+  border: IppResizeBorder<T>,
+}
+
+impl<T> IppImageResizeExt<T> for IppImageResize<T> where T: IppPixel {
+  fn create_with_layout(kind: IppImageResizeKind, layout: IppChannelLayout, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Self> {
+    let spec = IppResizeSpec::<T>::create(kind, layout, src_width, src_height, dst_width, dst_height)?;
+    IppImageResize::from_spec(Arc::new(spec))
+  }
+
+  fn try_resize_view(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    self.spec.try_resize_with_border(&mut self.work, src, 0, 0, dst, self.border)
+  }
+}
+
+impl<T> IppImageResize<T> where T: IppPixel {
+  /// Creates an antialiased resizer; see `IppResizeSpec::create_with_antialiasing`.
+  pub fn create_antialiased(kind: IppImageResizeKind, layout: IppChannelLayout, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<IppImageResize<T>> {
+    let spec = IppResizeSpec::<T>::create_with_antialiasing(kind, layout, true, src_width, src_height, dst_width, dst_height)?;
+    IppImageResize::from_spec(Arc::new(spec))
+  }
+
+  pub fn from_spec(spec: Arc<IppResizeSpec<T>>) -> IppResult<IppImageResize<T>> {
+    let work = spec.alloc_work_buf()?;
     Ok(IppImageResize{
       spec: spec,
-      buf:  buf,
-      kind: kind,
+      work: work,
+      bands:  vec![],
+      border: IppResizeBorder::repl(),
+    })
+  }
+
+  /// Creates another resizer for the same plan which shares the immutable
+  /// spec and owns a fresh work buffer, e.g. for use on another thread.
+  pub fn fork(&self) -> IppResult<IppImageResize<T>> {
+    IppImageResize::from_spec(self.spec.clone())
+  }
+
+  pub fn spec(&self) -> &Arc<IppResizeSpec<T>> {
+    &self.spec
+  }
+
+  pub fn border(&self) -> IppResizeBorder<T> {
+    self.border
+  }
+
+  /// Sets the border handling used by subsequent resizes.
+  pub fn set_border(&mut self, border: IppResizeBorder<T>) {
+    self.border = border;
+  }
+
+  pub fn resize_roi(&mut self, src: &IppImageView<T>, src_x: usize, src_y: usize, dst: &mut IppImageViewMut<T>) {
+    self.try_resize_roi(src, src_x, src_y, dst).unwrap();
+  }
+
+  /// Resizes the ROI of `src` at (`src_x`, `src_y`); see
+  /// `IppResizeSpec::try_resize_with_border`.
+  pub fn try_resize_roi(&mut self, src: &IppImageView<T>, src_x: usize, src_y: usize, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    self.spec.try_resize_with_border(&mut self.work, src, src_x, src_y, dst, self.border)
+  }
+
+  pub fn resize_parallel(&mut self, src: &IppImageBuf<T>, dst: &mut IppImageBuf<T>, num_threads: usize) {
+    self.try_resize_parallel(src, dst, num_threads).unwrap();
+  }
+
+  /// Like `try_resize`, but splits the destination into `num_threads` bands
+  /// of rows which are resized concurrently. Band work buffers are kept for
+  /// subsequent calls with the same thread count.
+  pub fn try_resize_parallel(&mut self, src: &IppImageBuf<T>, dst: &mut IppImageBuf<T>, num_threads: usize) -> IppResult<()> {
+    let num_bands = max(1, min(num_threads, self.spec.dst.1));
+    if self.bands.len() != num_bands {
+      self.bands = self.spec.alloc_band_work_bufs(num_bands)?;
+    }
+    self.spec.try_resize_parallel_with_border(&mut self.bands, &src.view(), 0, 0, &mut dst.view_mut(), self.border)
+  }
+}
+
+// Level sizes of a pyramid which halves the src size toward the dst size.
+fn downsample_sizes(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Vec<(usize, usize)> {
+  let mut sizes = vec![(src_width, src_height)];
+  let mut prev_width = src_width;
+  let mut prev_height = src_height;
+  while prev_width > dst_width || prev_height > dst_height {
+    let next_width = if prev_width >= 2 * dst_width {
+      prev_width.div_ceil(2)
+    } else {
+      dst_width
+    };
+    let next_height = if prev_height >= 2 * dst_height {
+      prev_height.div_ceil(2)
+    } else {
+      dst_height
+    };
+    /*println!("DEBUG: ipp: pyramid: level: {} x {} -> {} x {}",
+        prev_width, prev_height, next_width, next_height);*/
+    sizes.push((next_width, next_height));
+    prev_width = next_width;
+    prev_height = next_height;
+  }
+  sizes
+}
+
+// Level buffers and the resizers between them.
+type PyramidLevels<T> = (Vec<IppImageBuf<T>>, Vec<IppImageResize<T>>);
+
+// Allocates a buffer for each level size and a resizer between consecutive
+// levels, using `final_kind` for the step to the last level and `step_kind`
+// for the others. Level buffers of `reuse` are kept where their size matches,
+// and specs are taken from `cache` if one is given. `reuse` is only taken
+// from on success.
+fn pyramid_levels<T>(sizes: &[(usize, usize)], step_kind: IppImageResizeKind, final_kind: IppImageResizeKind, reuse: &mut Vec<IppImageBuf<T>>, mut cache: Option<&mut IppResizeCache>) -> IppResult<PyramidLevels<T>> where T: IppPixel {
+  let num_steps = sizes.len() - 1;
+  let mut ops = vec![];
+  for k in 0 .. num_steps {
+    let (prev_width, prev_height) = sizes[k];
+    let (next_width, next_height) = sizes[k+1];
+    let kind = if k + 1 == num_steps {
+      final_kind
+    } else {
+      step_kind
+    };
+    let op = match cache {
+      Some(ref mut cache) => cache.resizer(kind, IppChannelLayout::C1, prev_width, prev_height, next_width, next_height)?,
+      None => IppImageResize::<T>::create(kind, prev_width, prev_height, next_width, next_height)?,
+    };
+    ops.push(op);
+  }
+  let mut fresh = vec![];
+  for (k, &(width, height)) in sizes.iter().enumerate() {
+    match reuse.get(k) {
+      Some(buf) if buf.width() == width && buf.height() == height => fresh.push(None),
+      _ => fresh.push(Some(IppImageBuf::<T>::try_alloc(width, height)?)),
+    }
+  }
+  let mut reuse = take(reuse).into_iter();
+  let bufs = fresh.into_iter().map(|buf| {
+    let old = reuse.next();
+    buf.or(old).unwrap()
+  }).collect();
+  Ok((bufs, ops))
+}
+
+// Writes `src` into the first level, resizes through every level, and reads
+// the last level into `dst`.
+fn pyramid_run<T>(bufs: &mut [IppImageBuf<T>], ops: &mut [IppImageResize<T>], src: &[T], dst: &mut [T]) -> IppResult<()> where T: IppPixel {
+  let num_steps = ops.len();
+  bufs[0].try_write(src)?;
+  for k in 0 .. num_steps {
+    let (prev_bufs, next_bufs) = bufs.split_at_mut(k+1);
+    ops[k].try_resize(&prev_bufs[k], &mut next_bufs[0])?;
+  }
+  bufs[num_steps].try_read(dst)?;
+  Ok(())
+}
+
+pub struct IppImageDownsamplePyramid<T> where T: Copy {
+  bufs: Vec<IppImageBuf<T>>,
+  ops:  Vec<IppImageResize<T>>,
+  kinds: (IppImageResizeKind, IppImageResizeKind),
+  src:  (usize, usize),
+  dst:  (usize, usize),
+}
+
+impl<T> IppImageDownsamplePyramid<T> where T: IppPixel {
+  pub fn new(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Self {
+    Self::try_new(src_width, src_height, dst_width, dst_height).unwrap()
+  }
+
+  pub fn try_new(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Self> {
+    Self::try_new_with_kinds(IppImageResizeKind::Linear, IppImageResizeKind::Linear, src_width, src_height, dst_width, dst_height)
+  }
+
+  pub fn new_with_kinds(halving_kind: IppImageResizeKind, final_kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Self {
+    Self::try_new_with_kinds(halving_kind, final_kind, src_width, src_height, dst_width, dst_height).unwrap()
+  }
+
+  /// Creates a pyramid which resizes with `halving_kind` for every level but
+  /// the last, and with `final_kind` for the last level, which produces the
+  /// dst size.
+  pub fn try_new_with_kinds(halving_kind: IppImageResizeKind, final_kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Self> {
+    ipp_size(src_width, src_height)?;
+    ipp_size(dst_width, dst_height)?;
+    if !(src_width >= dst_width && src_height >= dst_height) {
+      return Err(IppError::InvalidArg("downsample pyramid: dst size must not exceed src size"));
+    }
+    let sizes = downsample_sizes(src_width, src_height, dst_width, dst_height);
+    let (bufs, ops) = pyramid_levels(&sizes, halving_kind, final_kind, &mut vec![], None)?;
+    Ok(IppImageDownsamplePyramid{
+      bufs: bufs,
+      ops:  ops,
+      kinds: (halving_kind, final_kind),
       src:  (src_width, src_height),
       dst:  (dst_width, dst_height),
-      _mrk: PhantomData,
     })
   }
 
-  fn resize(&mut self, src: &IppImageBuf<f32>, dst: &mut IppImageBuf<f32>) {
-    assert!(self.src.0 <= src.width);
-    assert!(self.src.1 <= src.height);
-    assert!(self.dst.0 <= dst.width);
-    assert!(self.dst.1 <= dst.height);
-    match self.kind {
-      IppImageResizeKind::Linear => {
-        let status = unsafe { ippiResizeLinear_32f_C1R(
-            src.ptr,
-            src.pitch as _,
-            dst.ptr,
-            dst.pitch as _,
-            IppiPoint{x: 0, y: 0},
-            IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
-            IppiBorderType::ippBorderRepl,
-            null(),
-            self.spec.as_ptr(),
-            self.buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Cubic{..} => {
-        let status = unsafe { ippiResizeCubic_32f_C1R(
-            src.ptr,
-            src.pitch as _,
-            dst.ptr,
-            dst.pitch as _,
-            IppiPoint{x: 0, y: 0},
-            IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
-            IppiBorderType::ippBorderRepl,
-            null(),
-            self.spec.as_ptr(),
-            self.buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
-      IppImageResizeKind::Lanczos{..} => {
-        let status = unsafe { ippiResizeLanczos_32f_C1R(
-            src.ptr,
-            src.pitch as _,
-            dst.ptr,
-            dst.pitch as _,
-            IppiPoint{x: 0, y: 0},
-            IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
-            IppiBorderType::ippBorderRepl,
-            null(),
-            self.spec.as_ptr(),
-            self.buf.as_mut_ptr(),
-        ) };
-        assert!(status.is_ok());
-      }
+  pub fn set_src_size(&mut self, cache: &mut IppResizeCache, src_width: usize, src_height: usize) {
+    self.try_set_src_size(cache, src_width, src_height).unwrap();
+  }
+
+  /// Re-plans the pyramid for a new src size, taking resize specs from
+  /// `cache` and keeping level buffers whose size is unchanged. On error the
+  /// previous plan is kept.
+  pub fn try_set_src_size(&mut self, cache: &mut IppResizeCache, src_width: usize, src_height: usize) -> IppResult<()> {
+    if (src_width, src_height) == self.src {
+      return Ok(());
+    }
+    ipp_size(src_width, src_height)?;
+    if !(src_width >= self.dst.0 && src_height >= self.dst.1) {
+      return Err(IppError::InvalidArg("downsample pyramid: dst size must not exceed src size"));
+    }
+    let sizes = downsample_sizes(src_width, src_height, self.dst.0, self.dst.1);
+    let (bufs, ops) = pyramid_levels(&sizes, self.kinds.0, self.kinds.1, &mut self.bufs, Some(cache))?;
+    self.bufs = bufs;
+    self.ops = ops;
+    self.src = (src_width, src_height);
+    Ok(())
+  }
+
+  pub fn downsample_cached(&mut self, cache: &mut IppResizeCache, src_width: usize, src_height: usize, src: &[T], dst: &mut [T]) {
+    self.try_downsample_cached(cache, src_width, src_height, src, dst).unwrap();
+  }
+
+  /// Downsamples a `src_width` x `src_height` image of any size to the dst
+  /// size; see `try_set_src_size`.
+  pub fn try_downsample_cached(&mut self, cache: &mut IppResizeCache, src_width: usize, src_height: usize, src: &[T], dst: &mut [T]) -> IppResult<()> {
+    self.try_set_src_size(cache, src_width, src_height)?;
+    self.try_downsample(src, dst)
+  }
+
+  pub fn downsample(&mut self, src: &[T], dst: &mut [T]) {
+    self.try_downsample(src, dst).unwrap();
+  }
+
+  pub fn try_downsample(&mut self, src: &[T], dst: &mut [T]) -> IppResult<()> {
+    if self.src.0 * self.src.1 != src.len() {
+      return Err(IppError::BufferLen{expected: self.src.0 * self.src.1, actual: src.len()});
     }
+    if self.dst.0 * self.dst.1 != dst.len() {
+      return Err(IppError::BufferLen{expected: self.dst.0 * self.dst.1, actual: dst.len()});
+    }
+    pyramid_run(&mut self.bufs, &mut self.ops, src, dst)
+  }
+
+  /// Number of levels, including the source level.
+  pub fn num_levels(&self) -> usize {
+    self.bufs.len()
+  }
+
+  /// The image at level `k` as of the last `downsample`; level 0 is the
+  /// source and the last level has the dst size.
+  pub fn level(&self, k: usize) -> Option<IppImageView<'_, T>> {
+    self.bufs.get(k).map(|buf| buf.view())
+  }
+
+  pub fn levels(&self) -> impl Iterator<Item=IppImageView<'_, T>> {
+    self.bufs.iter().map(|buf| buf.view())
   }
 }
 
-pub struct IppImageDownsamplePyramid<T> where T: Copy {
+pub struct IppImageUpsamplePyramid<T> where T: Copy {
   bufs: Vec<IppImageBuf<T>>,
   ops:  Vec<IppImageResize<T>>,
   src:  (usize, usize),
   dst:  (usize, usize),
 }
 
-impl IppImageDownsamplePyramid<u8> {
+impl<T> IppImageUpsamplePyramid<T> where T: IppPixel {
   pub fn new(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Self {
-    assert!(src_width >= dst_width);
-    assert!(src_height >= dst_height);
-    let mut bufs = vec![];
-    let mut ops = vec![];
-    bufs.push(IppImageBuf::<u8>::alloc(src_width, src_height));
+    Self::try_new(src_width, src_height, dst_width, dst_height).unwrap()
+  }
+
+  pub fn try_new(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Self> {
+    Self::try_new_with_kinds(IppImageResizeKind::Linear, IppImageResizeKind::Linear, src_width, src_height, dst_width, dst_height)
+  }
+
+  pub fn new_with_kinds(doubling_kind: IppImageResizeKind, final_kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> Self {
+    Self::try_new_with_kinds(doubling_kind, final_kind, src_width, src_height, dst_width, dst_height).unwrap()
+  }
+
+  /// Creates a pyramid which doubles the size with `doubling_kind` for every
+  /// level but the last, and resizes to the dst size with `final_kind`.
+  pub fn try_new_with_kinds(doubling_kind: IppImageResizeKind, final_kind: IppImageResizeKind, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<Self> {
+    ipp_size(src_width, src_height)?;
+    ipp_size(dst_width, dst_height)?;
+    if !(src_width <= dst_width && src_height <= dst_height) {
+      return Err(IppError::InvalidArg("upsample pyramid: src size must not exceed dst size"));
+    }
+    let mut sizes = vec![(src_width, src_height)];
     let mut prev_width = src_width;
     let mut prev_height = src_height;
-    while prev_width > dst_width || prev_height > dst_height {
-      let next_width = if prev_width >= 2 * dst_width {
-        (prev_width + 1) / 2
+    while prev_width < dst_width || prev_height < dst_height {
+      let next_width = if 2 * prev_width <= dst_width {
+        2 * prev_width
       } else {
         dst_width
       };
-      let next_height = if prev_height >= 2 * dst_height {
-        (prev_height + 1) / 2
+      let next_height = if 2 * prev_height <= dst_height {
+        2 * prev_height
       } else {
         dst_height
       };
-      /*println!("DEBUG: ipp: pyramid: level: {} x {} -> {} x {}",
-          prev_width, prev_height, next_width, next_height);*/
-      bufs.push(IppImageBuf::<u8>::alloc(next_width, next_height));
-      ops.push(IppImageResize::<u8>::create(IppImageResizeKind::Linear, prev_width, prev_height, next_width, next_height).unwrap());
+      sizes.push((next_width, next_height));
       prev_width = next_width;
       prev_height = next_height;
     }
-    IppImageDownsamplePyramid{
+    let (bufs, ops) = pyramid_levels(&sizes, doubling_kind, final_kind, &mut vec![], None)?;
+    Ok(IppImageUpsamplePyramid{
       bufs: bufs,
       ops:  ops,
       src:  (src_width, src_height),
       dst:  (dst_width, dst_height),
+    })
+  }
+
+  pub fn upsample(&mut self, src: &[T], dst: &mut [T]) {
+    self.try_upsample(src, dst).unwrap();
+  }
+
+  pub fn try_upsample(&mut self, src: &[T], dst: &mut [T]) -> IppResult<()> {
+    if self.src.0 * self.src.1 != src.len() {
+      return Err(IppError::BufferLen{expected: self.src.0 * self.src.1, actual: src.len()});
+    }
+    if self.dst.0 * self.dst.1 != dst.len() {
+      return Err(IppError::BufferLen{expected: self.dst.0 * self.dst.1, actual: dst.len()});
+    }
+    pyramid_run(&mut self.bufs, &mut self.ops, src, dst)
+  }
+
+  /// Number of levels, including the source level.
+  pub fn num_levels(&self) -> usize {
+    self.bufs.len()
+  }
+
+  /// The image at level `k` as of the last `upsample`; level 0 is the source
+  /// and the last level has the dst size.
+  pub fn level(&self, k: usize) -> Option<IppImageView<'_, T>> {
+    self.bufs.get(k).map(|buf| buf.view())
+  }
+
+  pub fn levels(&self) -> impl Iterator<Item=IppImageView<'_, T>> {
+    self.bufs.iter().map(|buf| buf.view())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn check_separates_warnings_from_errors() {
+    assert_eq!(ipp_take_warning(), None);
+    assert_eq!(ipp_check(IppStatus::IppStsNoErr), Ok(None));
+    assert_eq!(ipp_take_warning(), None);
+    assert_eq!(ipp_check(IppStatus::IppStsNoOperation), Ok(Some(IppWarning::NoOperation)));
+    assert_eq!(ipp_check(IppStatus(42)), Ok(Some(IppWarning::UnknownStatus(42))));
+    assert_eq!(ipp_take_warning(), Some(IppWarning::UnknownStatus(42)));
+    assert_eq!(ipp_take_warning(), None);
+    assert_eq!(ipp_check(IppStatus::IppStsSizeErr), Err(IppError::Size));
+    assert_eq!(ipp_check(IppStatus(-42)), Err(IppError::UnknownStatus(-42)));
+    assert_eq!(ipp_take_warning(), None);
+  }
+
+  #[test]
+  fn size_rejects_zero_and_overflow() {
+    let max = c_int::MAX as usize;
+    assert_eq!(ipp_size(0, 1).err(), Some(IppError::InvalidSize{width: 0, height: 1}));
+    assert_eq!(ipp_size(1, 0).err(), Some(IppError::InvalidSize{width: 1, height: 0}));
+    assert_eq!(ipp_size(max + 1, 1).err(), Some(IppError::InvalidSize{width: max + 1, height: 1}));
+    assert_eq!(ipp_size(1, max + 1).err(), Some(IppError::InvalidSize{width: 1, height: max + 1}));
+    let size = ipp_size(max, 1).unwrap();
+    assert_eq!((size.width, size.height), (c_int::MAX, 1));
+  }
+
+  #[test]
+  fn copy2d_checks_last_row() {
+    let src = [0u8; 6];
+    let mut dst = [0u8; 7];
+    // A 3 x 2 rectangle with a pitch of 4 ends at element 4 + 3 = 7.
+    assert_eq!(ipp_try_copy2d_u8(3, 2, 0, 0, 4, &src, 0, 0, 4, &mut dst), Err(IppError::BufferLen{expected: 7, actual: 6}));
+    let src = [0u8; 7];
+    let mut dst = [0u8; 8];
+    assert_eq!(ipp_try_copy2d_u8(3, 2, 0, 0, 4, &src, 1, 0, 4, &mut dst[.. 7]), Err(IppError::BufferLen{expected: 8, actual: 7}));
+    assert_eq!(ipp_try_copy2d_u8(3, 2, 0, 0, 4, &src, 0, 1, 4, &mut dst), Err(IppError::BufferLen{expected: 11, actual: 8}));
+  }
+
+  #[test]
+  fn copy2d_rejects_bad_pitch_and_offsets() {
+    let src = [0.0f32; 16];
+    let mut dst = [0.0f32; 16];
+    let c1 = IppChannelLayout::C1;
+    assert!(matches!(ipp_try_copy2d(c1, 5, 2, 0, 0, 4, &src, 0, 0, 8, &mut dst), Err(IppError::InvalidArg(_))));
+    assert_eq!(ipp_try_copy2d(c1, 2, 2, usize::MAX, 0, 4, &src, 0, 0, 4, &mut dst), Err(IppError::OutOfBounds));
+    assert_eq!(ipp_try_copy2d(c1, 2, 2, 0, 0, 4, &src, 0, usize::MAX, 4, &mut dst), Err(IppError::OutOfBounds));
+    assert_eq!(ipp_try_copy2d(c1, 2, 2, 0, 0, usize::MAX, &src, 0, 0, 4, &mut dst), Err(IppError::OutOfBounds));
+  }
+
+  #[test]
+  fn resize_parallel_matches_resize() {
+    let c1 = IppChannelLayout::C1;
+    let (src_width, src_height) = (37, 23);
+    let pixels: Vec<u8> = (0 .. src_width * src_height).map(|i| (i * 31 % 251) as u8).collect();
+    let src = IppImageView::from_slice(&pixels, src_width, src_height, src_width, c1).unwrap();
+    for &kind in &[IppImageResizeKind::Linear, IppImageResizeKind::Cubic{b: 0.0, c: 0.5}] {
+      for &(dst_width, dst_height) in &[(50, 41), (17, 11)] {
+        let spec = IppResizeSpec::<u8>::create(kind, c1, src_width, src_height, dst_width, dst_height).unwrap();
+        let mut expected = vec![0; dst_width * dst_height];
+        {
+          let mut dst = IppImageViewMut::from_slice_mut(&mut expected, dst_width, dst_height, dst_width, c1).unwrap();
+          let mut work = spec.alloc_work_buf().unwrap();
+          spec.try_resize(&mut work, &src, &mut dst).unwrap();
+        }
+        for &num_bands in &[1, 2, 3, 7] {
+          let mut works = spec.alloc_band_work_bufs(num_bands).unwrap();
+          let mut actual = vec![0; dst_width * dst_height];
+          {
+            let mut dst = IppImageViewMut::from_slice_mut(&mut actual, dst_width, dst_height, dst_width, c1).unwrap();
+            spec.try_resize_parallel(&mut works, &src, &mut dst).unwrap();
+          }
+          assert!(actual == expected, "{} bands differ for {} x {}", num_bands, dst_width, dst_height);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn upsample_pyramid_rejects_zero_sizes() {
+    assert_eq!(IppImageUpsamplePyramid::<u8>::try_new(0, 4, 8, 8).err(), Some(IppError::InvalidSize{width: 0, height: 4}));
+    assert_eq!(IppImageUpsamplePyramid::<u8>::try_new(4, 0, 8, 8).err(), Some(IppError::InvalidSize{width: 4, height: 0}));
+    assert_eq!(IppImageUpsamplePyramid::<u8>::try_new(4, 4, 0, 8).err(), Some(IppError::InvalidSize{width: 0, height: 8}));
+    assert_eq!(IppImageUpsamplePyramid::<f32>::try_new(0, 0, 0, 0).err(), Some(IppError::InvalidSize{width: 0, height: 0}));
+  }
+
+  fn create_err<T>(kind: IppImageResizeKind, layout: IppChannelLayout, antialiasing: bool, src: (usize, usize), dst: (usize, usize)) -> IppError where T: IppPixel {
+    match IppResizeSpec::<T>::create_with_antialiasing(kind, layout, antialiasing, src.0, src.1, dst.0, dst.1) {
+      Ok(_) => panic!("resize spec was created"),
+      Err(e) => e,
     }
   }
 
-  pub fn downsample(&mut self, src: &[u8], dst: &mut [u8]) {
-    assert_eq!(self.src.0 * self.src.1, src.len());
-    assert_eq!(self.dst.0 * self.dst.1, dst.len());
-    let num_levels = self.ops.len();
-    self.bufs[0].write(src);
-    for k in 0 .. num_levels {
-      let (prev_bufs, mut next_bufs) = self.bufs.split_at_mut(k+1);
-      self.ops[k].resize(&prev_bufs[k], &mut next_bufs[0]);
+  #[test]
+  fn resize_create_rejects_bad_sizes() {
+    let max = c_int::MAX as usize;
+    let kind = IppImageResizeKind::Linear;
+    let c1 = IppChannelLayout::C1;
+    assert_eq!(create_err::<u8>(kind, c1, false, (0, 8), (4, 4)), IppError::InvalidSize{width: 0, height: 8});
+    assert_eq!(create_err::<u8>(kind, c1, false, (8, 8), (4, 0)), IppError::InvalidSize{width: 4, height: 0});
+    assert_eq!(create_err::<u8>(kind, c1, false, (max + 1, 8), (4, 4)), IppError::InvalidSize{width: max + 1, height: 8});
+    assert_eq!(create_err::<u8>(kind, c1, false, (8, 8), (4, max + 1)), IppError::InvalidSize{width: 4, height: max + 1});
+  }
+
+  #[test]
+  fn resize_create_rejects_bad_params() {
+    let c1 = IppChannelLayout::C1;
+    for &nlobes in &[1, 4] {
+      let kind = IppImageResizeKind::Lanczos{nlobes: nlobes};
+      assert!(matches!(create_err::<u8>(kind, c1, false, (8, 8), (4, 4)), IppError::Unsupported(_)));
     }
-    self.bufs[num_levels].read(dst);
+    assert!(matches!(create_err::<u8>(IppImageResizeKind::Super, c1, false, (8, 8), (16, 4)), IppError::Unsupported(_)));
+    assert!(matches!(create_err::<u8>(IppImageResizeKind::Super, c1, false, (8, 8), (4, 16)), IppError::Unsupported(_)));
+    assert!(matches!(create_err::<u8>(IppImageResizeKind::Linear, IppChannelLayout::AC4, false, (8, 8), (4, 4)), IppError::Unsupported(_)));
+    assert!(matches!(create_err::<f64>(IppImageResizeKind::Lanczos{nlobes: 3}, c1, false, (8, 8), (4, 4)), IppError::Unsupported(_)));
+  }
+
+  #[test]
+  fn resize_create_rejects_bad_antialiasing() {
+    let c1 = IppChannelLayout::C1;
+    assert!(matches!(create_err::<u8>(IppImageResizeKind::Nearest, c1, true, (8, 8), (4, 4)), IppError::Unsupported(_)));
+    assert!(matches!(create_err::<f32>(IppImageResizeKind::Super, c1, true, (8, 8), (4, 4)), IppError::Unsupported(_)));
+    assert!(matches!(create_err::<u16>(IppImageResizeKind::Linear, c1, true, (8, 8), (4, 4)), IppError::Unsupported(_)));
   }
 }
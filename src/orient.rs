@@ -0,0 +1,298 @@
+use ffi::*;
+use {IppError, IppPixel, IppResult, ipp_check, ipp_size};
+use view::{IppImageView, IppImageViewMut, ipp_try_copy_image};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IppMirrorAxis {
+  /// Flip left to right.
+  Horizontal,
+  /// Flip top to bottom.
+  Vertical,
+  /// Flip both ways, i.e. rotate by 180 degrees.
+  Both,
+}
+
+impl IppMirrorAxis {
+  // IPP names the axis being flipped about, not the direction of the flip.
+  fn ipp_axis(&self) -> IppiAxis {
+    match *self {
+      IppMirrorAxis::Horizontal => IppiAxis::ippAxsVertical,
+      IppMirrorAxis::Vertical   => IppiAxis::ippAxsHorizontal,
+      IppMirrorAxis::Both       => IppiAxis::ippAxsBoth,
+    }
+  }
+}
+
+/// Clockwise rotations by multiples of 90 degrees.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IppRotation {
+  Cw90,
+  Cw180,
+  Cw270,
+}
+
+/// The transform which displays an image upright, by EXIF orientation code.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IppOrientation {
+  /// Code 1.
+  Normal,
+  /// Code 2.
+  MirrorHorizontal,
+  /// Code 3.
+  Rotate180,
+  /// Code 4.
+  MirrorVertical,
+  /// Code 5; mirror about the main diagonal.
+  Transpose,
+  /// Code 6; rotate by 90 degrees clockwise.
+  Rotate90,
+  /// Code 7; mirror about the anti-diagonal.
+  Transverse,
+  /// Code 8; rotate by 270 degrees clockwise.
+  Rotate270,
+}
+
+impl IppOrientation {
+  pub fn from_exif(code: u16) -> Option<IppOrientation> {
+    match code {
+      1 => Some(IppOrientation::Normal),
+      2 => Some(IppOrientation::MirrorHorizontal),
+      3 => Some(IppOrientation::Rotate180),
+      4 => Some(IppOrientation::MirrorVertical),
+      5 => Some(IppOrientation::Transpose),
+      6 => Some(IppOrientation::Rotate90),
+      7 => Some(IppOrientation::Transverse),
+      8 => Some(IppOrientation::Rotate270),
+      _ => None,
+    }
+  }
+
+  pub fn exif_code(&self) -> u16 {
+    match *self {
+      IppOrientation::Normal            => 1,
+      IppOrientation::MirrorHorizontal  => 2,
+      IppOrientation::Rotate180         => 3,
+      IppOrientation::MirrorVertical    => 4,
+      IppOrientation::Transpose         => 5,
+      IppOrientation::Rotate90          => 6,
+      IppOrientation::Transverse        => 7,
+      IppOrientation::Rotate270         => 8,
+    }
+  }
+
+  /// Whether the transform exchanges width and height.
+  pub fn swaps_axes(&self) -> bool {
+    matches!(*self,
+        IppOrientation::Transpose   |
+        IppOrientation::Rotate90    |
+        IppOrientation::Transverse  |
+        IppOrientation::Rotate270)
+  }
+
+  /// The size of a `width` x `height` image after the transform.
+  pub fn oriented_size(&self, width: usize, height: usize) -> (usize, usize) {
+    if self.swaps_axes() {
+      (height, width)
+    } else {
+      (width, height)
+    }
+  }
+}
+
+impl From<IppRotation> for IppOrientation {
+  fn from(rotation: IppRotation) -> IppOrientation {
+    match rotation {
+      IppRotation::Cw90   => IppOrientation::Rotate90,
+      IppRotation::Cw180  => IppOrientation::Rotate180,
+      IppRotation::Cw270  => IppOrientation::Rotate270,
+    }
+  }
+}
+
+pub fn ipp_mirror_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, axis: IppMirrorAxis) where T: IppPixel {
+  ipp_try_mirror_image(src, dst, axis).unwrap();
+}
+
+/// Writes `src` mirrored about `axis` into `dst`; both views must have the
+/// same size and layout. IPP has no kernels for f64 images with more than one
+/// channel, so those are mirrored by a slower scalar loop.
+pub fn ipp_try_mirror_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, axis: IppMirrorAxis) -> IppResult<()> where T: IppPixel {
+  if src.layout() != dst.layout() {
+    return Err(IppError::InvalidArg("mirror: src and dst layouts differ"));
+  }
+  if src.width() != dst.width() || src.height() != dst.height() {
+    return Err(IppError::InvalidArg("mirror: src and dst sizes differ"));
+  }
+  let size = ipp_size(src.width(), src.height())?;
+  let status = unsafe { T::ipp_mirror(
+      src.layout(),
+      src.as_ptr(),
+      src.pitch() as _,
+      dst.as_mut_ptr(),
+      dst.pitch() as _,
+      size,
+      axis.ipp_axis(),
+  ) };
+  ipp_check(status)?;
+  Ok(())
+}
+
+pub fn ipp_mirror_image_inplace<T>(img: &mut IppImageViewMut<T>, axis: IppMirrorAxis) where T: IppPixel {
+  ipp_try_mirror_image_inplace(img, axis).unwrap();
+}
+
+pub fn ipp_try_mirror_image_inplace<T>(img: &mut IppImageViewMut<T>, axis: IppMirrorAxis) -> IppResult<()> where T: IppPixel {
+  let size = ipp_size(img.width(), img.height())?;
+  let status = unsafe { T::ipp_mirror_inplace(
+      img.layout(),
+      img.as_mut_ptr(),
+      img.pitch() as _,
+      size,
+      axis.ipp_axis(),
+  ) };
+  ipp_check(status)?;
+  Ok(())
+}
+
+pub fn ipp_transpose_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) where T: IppPixel {
+  ipp_try_transpose_image(src, dst).unwrap();
+}
+
+/// Writes the transpose of `src` into `dst`, which must be `src.height()`
+/// pixels wide and `src.width()` pixels high. AC4 images keep the alpha
+/// channel of `dst`. IPP has no AC4 transposes nor kernels for f64 images
+/// with more than one channel, so those run a slower scalar loop.
+pub fn ipp_try_transpose_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> where T: IppPixel {
+  if src.layout() != dst.layout() {
+    return Err(IppError::InvalidArg("transpose: src and dst layouts differ"));
+  }
+  if src.width() != dst.height() || src.height() != dst.width() {
+    return Err(IppError::InvalidArg("transpose: dst size is not the transposed src size"));
+  }
+  let size = ipp_size(src.width(), src.height())?;
+  let status = unsafe { T::ipp_transpose(
+      src.layout(),
+      src.as_ptr(),
+      src.pitch() as _,
+      dst.as_mut_ptr(),
+      dst.pitch() as _,
+      size,
+  ) };
+  ipp_check(status)?;
+  Ok(())
+}
+
+pub fn ipp_transpose_image_inplace<T>(img: &mut IppImageViewMut<T>) where T: IppPixel {
+  ipp_try_transpose_image_inplace(img).unwrap();
+}
+
+/// Transposes a square image in place.
+pub fn ipp_try_transpose_image_inplace<T>(img: &mut IppImageViewMut<T>) -> IppResult<()> where T: IppPixel {
+  if img.width() != img.height() {
+    return Err(IppError::InvalidArg("transpose: in-place transpose requires a square image"));
+  }
+  let size = ipp_size(img.width(), img.height())?;
+  let status = unsafe { T::ipp_transpose_inplace(
+      img.layout(),
+      img.as_mut_ptr(),
+      img.pitch() as _,
+      size,
+  ) };
+  ipp_check(status)?;
+  Ok(())
+}
+
+pub fn ipp_rotate_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, rotation: IppRotation) where T: IppPixel {
+  ipp_try_rotate_image(src, dst, rotation).unwrap();
+}
+
+pub fn ipp_try_rotate_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, rotation: IppRotation) -> IppResult<()> where T: IppPixel {
+  ipp_try_orient_image(src, dst, rotation.into())
+}
+
+pub fn ipp_orient_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, orientation: IppOrientation) where T: IppPixel {
+  ipp_try_orient_image(src, dst, orientation).unwrap();
+}
+
+/// Writes `src` transformed by `orientation` into `dst`, whose size must be
+/// `orientation.oriented_size(src.width(), src.height())`. Transforms which
+/// swap axes are a transpose followed by an in-place mirror of `dst`.
+pub fn ipp_try_orient_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>, orientation: IppOrientation) -> IppResult<()> where T: IppPixel {
+  match orientation {
+    IppOrientation::Normal            => ipp_try_copy_image(src, dst),
+    IppOrientation::MirrorHorizontal  => ipp_try_mirror_image(src, dst, IppMirrorAxis::Horizontal),
+    IppOrientation::Rotate180         => ipp_try_mirror_image(src, dst, IppMirrorAxis::Both),
+    IppOrientation::MirrorVertical    => ipp_try_mirror_image(src, dst, IppMirrorAxis::Vertical),
+    IppOrientation::Transpose         => ipp_try_transpose_image(src, dst),
+    IppOrientation::Rotate90          => {
+      ipp_try_transpose_image(src, dst)?;
+      ipp_try_mirror_image_inplace(dst, IppMirrorAxis::Horizontal)
+    }
+    IppOrientation::Transverse        => {
+      ipp_try_transpose_image(src, dst)?;
+      ipp_try_mirror_image_inplace(dst, IppMirrorAxis::Both)
+    }
+    IppOrientation::Rotate270         => {
+      ipp_try_transpose_image(src, dst)?;
+      ipp_try_mirror_image_inplace(dst, IppMirrorAxis::Vertical)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use IppChannelLayout;
+
+  #[test]
+  fn exif_code_round_trip() {
+    for code in 0 .. 10 {
+      match IppOrientation::from_exif(code) {
+        Some(orientation) => {
+          assert!((1 ..= 8).contains(&code));
+          assert_eq!(orientation.exif_code(), code);
+          let size = if code >= 5 { (3, 2) } else { (2, 3) };
+          assert_eq!(orientation.oriented_size(2, 3), size);
+        }
+        None => assert!(code == 0 || code == 9),
+      }
+    }
+  }
+
+  #[test]
+  fn orient_compositions() {
+    // An f64 C3 image has no IPP kernels, so this runs the host fallbacks.
+    let (width, height) = (2, 3);
+    let mut src = Vec::new();
+    for i in 0 .. width * height {
+      src.extend_from_slice(&[i as f64, i as f64 + 0.25, i as f64 + 0.5]);
+    }
+    let src_view = IppImageView::from_slice(&src, width, height, width * 24, IppChannelLayout::C3).unwrap();
+    // Maps each dst pixel to the src pixel it shows.
+    type SrcOf = fn (usize, usize) -> (usize, usize);
+    let cases: [(IppOrientation, SrcOf); 7] = [
+      (IppOrientation::MirrorHorizontal,  |x, y| (1 - x, y)),
+      (IppOrientation::Rotate180,         |x, y| (1 - x, 2 - y)),
+      (IppOrientation::MirrorVertical,    |x, y| (x, 2 - y)),
+      (IppOrientation::Transpose,         |x, y| (y, x)),
+      (IppOrientation::Rotate90,          |x, y| (y, 2 - x)),
+      (IppOrientation::Transverse,        |x, y| (1 - y, 2 - x)),
+      (IppOrientation::Rotate270,         |x, y| (1 - y, x)),
+    ];
+    for &(orientation, src_of) in cases.iter() {
+      let (dst_width, dst_height) = orientation.oriented_size(width, height);
+      let mut dst = vec![0.0; width * height * 3];
+      {
+        let mut dst_view = IppImageViewMut::from_slice_mut(&mut dst, dst_width, dst_height, dst_width * 24, IppChannelLayout::C3).unwrap();
+        ipp_try_orient_image(&src_view, &mut dst_view, orientation).unwrap();
+      }
+      for y in 0 .. dst_height {
+        for x in 0 .. dst_width {
+          let (src_x, src_y) = src_of(x, y);
+          let (d, s) = ((y * dst_width + x) * 3, (src_y * width + src_x) * 3);
+          assert_eq!(&dst[d .. d + 3], &src[s .. s + 3], "{:?} at ({}, {})", orientation, x, y);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,932 @@
+use ffi::*;
+use {IppChannelLayout, IppImageResizeKind};
+
+use libc::{c_int};
+
+use std::mem::{size_of, transmute};
+use std::ptr::{copy_nonoverlapping, null_mut, swap_nonoverlapping};
+
+mod sealed {
+  pub trait Sealed {}
+
+  impl Sealed for u8 {}
+  impl Sealed for u16 {}
+  impl Sealed for i16 {}
+  impl Sealed for f32 {}
+  impl Sealed for f64 {}
+}
+
+/// Pixel depths supported by IPP. Each impl carries the FFI entry points for
+/// one depth; `IppImageBuf` and `IppImageResize` are generic over this trait.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// The `unsafe` methods call IPP directly and share one contract: every
+/// image pointer must address `size.height` rows spaced `step` bytes apart,
+/// each holding `size.width` pixels of `layout` (`layout.num_channels()`
+/// elements of `Self` per pixel), valid for reads (src) or writes (dst) for
+/// the duration of the call; steps are positive and at least one row wide;
+/// sizes are positive; out pointers are valid for writes.
+pub trait IppPixel: Copy + Send + Sync + 'static + sealed::Sealed {
+  fn supports_layout(_layout: IppChannelLayout) -> bool { true }
+  fn supports_resize(_kind: IppImageResizeKind) -> bool { true }
+  fn supports_antialiasing() -> bool { false }
+
+  /// # Safety
+  ///
+  /// `pitch` must be valid for writes. The returned image, if not null, must
+  /// be released with `ippiFree`.
+  unsafe fn ipp_malloc(layout: IppChannelLayout, width: c_int, height: c_int, pitch: *mut c_int) -> *mut Self;
+  /// # Safety
+  ///
+  /// `src` and `dst` must be images of `size` and `layout` as described on
+  /// the trait, and must not overlap.
+  unsafe fn ipp_copy(layout: IppChannelLayout, src: *const Self, src_step: c_int, dst: *mut Self, dst_step: c_int, size: IppiSize) -> IppStatus;
+  /// # Safety
+  ///
+  /// `value` must point to `layout.num_channels()` elements, and `dst` must
+  /// be an image of `size` and `layout` as described on the trait.
+  unsafe fn ipp_set(layout: IppChannelLayout, value: *const Self, dst: *mut Self, dst_step: c_int, size: IppiSize) -> IppStatus;
+  /// # Safety
+  ///
+  /// `src` and `dst` must be images of `size` and `layout` as described on
+  /// the trait, and must not overlap.
+  unsafe fn ipp_mirror(layout: IppChannelLayout, src: *const Self, src_step: c_int, dst: *mut Self, dst_step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus;
+  /// # Safety
+  ///
+  /// `src_dst` must be an image of `size` and `layout` as described on the
+  /// trait.
+  unsafe fn ipp_mirror_inplace(layout: IppChannelLayout, src_dst: *mut Self, step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus;
+  /// # Safety
+  ///
+  /// `src` must be an image of `src_size` and `dst` one of the transposed
+  /// size, both of `layout` as described on the trait; they must not overlap.
+  unsafe fn ipp_transpose(layout: IppChannelLayout, src: *const Self, src_step: c_int, dst: *mut Self, dst_step: c_int, src_size: IppiSize) -> IppStatus;
+  /// # Safety
+  ///
+  /// `src_dst` must be a square image of `size` and `layout` as described on
+  /// the trait.
+  unsafe fn ipp_transpose_inplace(layout: IppChannelLayout, src_dst: *mut Self, step: c_int, size: IppiSize) -> IppStatus;
+
+  /// # Safety
+  ///
+  /// `spec_size` and `init_buf_size` must be valid for writes.
+  unsafe fn ipp_resize_get_size(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus;
+  /// # Safety
+  ///
+  /// `spec` must point to at least the spec size reported by
+  /// `ipp_resize_get_size` for the same sizes and interpolation.
+  unsafe fn ipp_resize_nearest_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  /// # Safety
+  ///
+  /// As for `ipp_resize_nearest_init`.
+  unsafe fn ipp_resize_linear_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  /// # Safety
+  ///
+  /// As for `ipp_resize_nearest_init`; `init_buf` must point to at least the
+  /// init buffer size reported by `ipp_resize_get_size`.
+  unsafe fn ipp_resize_cubic_init(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  /// # Safety
+  ///
+  /// As for `ipp_resize_cubic_init`.
+  unsafe fn ipp_resize_lanczos_init(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus;
+  /// # Safety
+  ///
+  /// As for `ipp_resize_nearest_init`.
+  unsafe fn ipp_resize_super_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus;
+  /// # Safety
+  ///
+  /// `spec` must have been initialized by one of the `ipp_resize_*_init`
+  /// methods of the same depth, and `buf_size` must be valid for writes.
+  unsafe fn ipp_resize_get_buffer_size(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus;
+  /// # Safety
+  ///
+  /// `spec` must be initialized as for `ipp_resize_get_buffer_size`, and
+  /// `src_offset` must be valid for writes.
+  unsafe fn ipp_resize_get_src_offset(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus;
+  /// # Safety
+  ///
+  /// `spec` must be initialized as for `ipp_resize_get_buffer_size`, and
+  /// `border_size` must be valid for writes.
+  unsafe fn ipp_resize_get_border_size(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus;
+  /// # Safety
+  ///
+  /// `spec` must be initialized for `kind` as for
+  /// `ipp_resize_get_buffer_size`, and `buf` must point to at least the
+  /// buffer size it reports for `dst_size`. `dst` must be an image of
+  /// `dst_size` and `layout` as described on the trait, and `src` must cover
+  /// every pixel the spec reads for that ROI, including border pixels which
+  /// are in memory. `border_value` must point to `layout.num_channels()`
+  /// elements when `border` is constant.
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn ipp_resize(
+      kind: IppImageResizeKind, layout: IppChannelLayout,
+      src: *const Self, src_step: c_int, dst: *mut Self, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const Self,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+  /// # Safety
+  ///
+  /// As for `ipp_resize`, with a spec initialized for antialiasing.
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn ipp_resize_antialiasing(
+      layout: IppChannelLayout,
+      src: *const Self, src_step: c_int, dst: *mut Self, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const Self,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus;
+}
+
+// Host fallbacks for layouts without a matching IPP kernel. Pixels are
+// `stride` elements apart, of which the first `count` are written, so that
+// AC4 images keep their alpha channel.
+unsafe fn host_pixel<T>(base: *mut T, step: c_int, stride: usize, x: usize, y: usize) -> *mut T {
+  (base as *mut u8).add(y * step as usize).cast::<T>().add(x * stride)
+}
+
+unsafe fn host_set<T>(stride: usize, count: usize, value: *const T, dst: *mut T, dst_step: c_int, size: IppiSize) -> IppStatus where T: Copy {
+  for y in 0 .. size.height as usize {
+    for x in 0 .. size.width as usize {
+      copy_nonoverlapping(value, host_pixel(dst, dst_step, stride, x, y), count);
+    }
+  }
+  IppStatus::IppStsNoErr
+}
+
+// The pixel which `flip` moves to (x, y), or `None` for diagonal axes.
+fn host_mirror_src(flip: IppiAxis, width: usize, height: usize, x: usize, y: usize) -> Option<(usize, usize)> {
+  match flip {
+    IppiAxis::ippAxsHorizontal  => Some((x, height - 1 - y)),
+    IppiAxis::ippAxsVertical    => Some((width - 1 - x, y)),
+    IppiAxis::ippAxsBoth        => Some((width - 1 - x, height - 1 - y)),
+    _ => None,
+  }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn host_mirror<T>(stride: usize, count: usize, src: *const T, src_step: c_int, dst: *mut T, dst_step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus where T: Copy {
+  let (width, height) = (size.width as usize, size.height as usize);
+  for y in 0 .. height {
+    for x in 0 .. width {
+      let (src_x, src_y) = match host_mirror_src(flip, width, height, x, y) {
+        Some(p) => p,
+        None => return IppStatus::IppStsNotSupportedModeErr,
+      };
+      copy_nonoverlapping(host_pixel(src as *mut T, src_step, stride, src_x, src_y), host_pixel(dst, dst_step, stride, x, y), count);
+    }
+  }
+  IppStatus::IppStsNoErr
+}
+
+unsafe fn host_mirror_inplace<T>(stride: usize, count: usize, src_dst: *mut T, step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus where T: Copy {
+  let (width, height) = (size.width as usize, size.height as usize);
+  for y in 0 .. height {
+    for x in 0 .. width {
+      let (src_x, src_y) = match host_mirror_src(flip, width, height, x, y) {
+        Some(p) => p,
+        None => return IppStatus::IppStsNotSupportedModeErr,
+      };
+      // Swap each pair once, from its earlier pixel in row-major order.
+      if (src_y, src_x) > (y, x) {
+        swap_nonoverlapping(host_pixel(src_dst, step, stride, src_x, src_y), host_pixel(src_dst, step, stride, x, y), count);
+      }
+    }
+  }
+  IppStatus::IppStsNoErr
+}
+
+unsafe fn host_transpose<T>(stride: usize, count: usize, src: *const T, src_step: c_int, dst: *mut T, dst_step: c_int, src_size: IppiSize) -> IppStatus where T: Copy {
+  for y in 0 .. src_size.height as usize {
+    for x in 0 .. src_size.width as usize {
+      copy_nonoverlapping(host_pixel(src as *mut T, src_step, stride, x, y), host_pixel(dst, dst_step, stride, y, x), count);
+    }
+  }
+  IppStatus::IppStsNoErr
+}
+
+unsafe fn host_transpose_inplace<T>(stride: usize, count: usize, src_dst: *mut T, step: c_int, size: IppiSize) -> IppStatus where T: Copy {
+  if size.width != size.height {
+    return IppStatus::IppStsSizeErr;
+  }
+  for y in 0 .. size.height as usize {
+    for x in y + 1 .. size.width as usize {
+      swap_nonoverlapping(host_pixel(src_dst, step, stride, x, y), host_pixel(src_dst, step, stride, y, x), count);
+    }
+  }
+  IppStatus::IppStsNoErr
+}
+
+impl IppPixel for u8 {
+  fn supports_antialiasing() -> bool {
+    true
+  }
+
+  unsafe fn ipp_malloc(layout: IppChannelLayout, width: c_int, height: c_int, pitch: *mut c_int) -> *mut u8 {
+    match layout {
+      IppChannelLayout::C1  => ippiMalloc_8u_C1(width, height, pitch),
+      IppChannelLayout::C3  => ippiMalloc_8u_C3(width, height, pitch),
+      IppChannelLayout::C4  => ippiMalloc_8u_C4(width, height, pitch),
+      IppChannelLayout::AC4 => ippiMalloc_8u_AC4(width, height, pitch),
+    }
+  }
+
+  unsafe fn ipp_copy(layout: IppChannelLayout, src: *const u8, src_step: c_int, dst: *mut u8, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiCopy_8u_C1R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiCopy_8u_C3R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiCopy_8u_C4R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiCopy_8u_AC4R(src, src_step, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_set(layout: IppChannelLayout, value: *const u8, dst: *mut u8, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiSet_8u_C1R(*value, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiSet_8u_C3R(value, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiSet_8u_C4R(value, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiSet_8u_AC4R(value, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_mirror(layout: IppChannelLayout, src: *const u8, src_step: c_int, dst: *mut u8, dst_step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_8u_C1R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_8u_C3R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_8u_C4R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_8u_AC4R(src, src_step, dst, dst_step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_mirror_inplace(layout: IppChannelLayout, src_dst: *mut u8, step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_8u_C1IR(src_dst, step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_8u_C3IR(src_dst, step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_8u_C4IR(src_dst, step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_8u_AC4IR(src_dst, step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_transpose(layout: IppChannelLayout, src: *const u8, src_step: c_int, dst: *mut u8, dst_step: c_int, src_size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_8u_C1R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C3  => ippiTranspose_8u_C3R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C4  => ippiTranspose_8u_C4R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::AC4 => host_transpose(4, 3, src, src_step, dst, dst_step, src_size),
+    }
+  }
+
+  unsafe fn ipp_transpose_inplace(layout: IppChannelLayout, src_dst: *mut u8, step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_8u_C1IR(src_dst, step, size),
+      IppChannelLayout::C3  => ippiTranspose_8u_C3IR(src_dst, step, size),
+      IppChannelLayout::C4  => ippiTranspose_8u_C4IR(src_dst, step, size),
+      IppChannelLayout::AC4 => host_transpose_inplace(4, 3, src_dst, step, size),
+    }
+  }
+
+  unsafe fn ipp_resize_get_size(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetSize_8u(src_size, dst_size, interpolation, antialiasing, spec_size, init_buf_size)
+  }
+
+  unsafe fn ipp_resize_nearest_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeNearestInit_8u(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_linear_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeLinearInit_8u(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_cubic_init(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeCubicInit_8u(src_size, dst_size, value_b, value_c, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_lanczos_init(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeLanczosInit_8u(src_size, dst_size, num_lobes, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_super_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeSuperInit_8u(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_get_buffer_size(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetBufferSize_8u(spec, dst_size, num_channels, buf_size)
+  }
+
+  unsafe fn ipp_resize_get_src_offset(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus {
+    ippiResizeGetSrcOffset_8u(spec, dst_offset, src_offset)
+  }
+
+  unsafe fn ipp_resize_get_border_size(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus {
+    ippiResizeGetBorderSize_8u(spec, border_size)
+  }
+
+  unsafe fn ipp_resize(
+      kind: IppImageResizeKind, layout: IppChannelLayout,
+      src: *const u8, src_step: c_int, dst: *mut u8, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const u8,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppImageResizeKind::Nearest, IppChannelLayout::C1)     => ippiResizeNearest_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C3)     => ippiResizeNearest_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C4)     => ippiResizeNearest_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C1)       => ippiResizeSuper_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C3)       => ippiResizeSuper_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C4)       => ippiResizeSuper_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C1)      => ippiResizeLinear_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C3)      => ippiResizeLinear_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C4)      => ippiResizeLinear_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C1)   => ippiResizeCubic_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C3)   => ippiResizeCubic_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C4)   => ippiResizeCubic_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C1) => ippiResizeLanczos_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C3) => ippiResizeLanczos_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C4) => ippiResizeLanczos_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_resize_antialiasing(
+      layout: IppChannelLayout,
+      src: *const u8, src_step: c_int, dst: *mut u8, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const u8,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus
+  {
+    match layout {
+      IppChannelLayout::C1  => ippiResizeAntialiasing_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      IppChannelLayout::C3  => ippiResizeAntialiasing_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      IppChannelLayout::C4  => ippiResizeAntialiasing_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+}
+
+impl IppPixel for u16 {
+  unsafe fn ipp_malloc(layout: IppChannelLayout, width: c_int, height: c_int, pitch: *mut c_int) -> *mut u16 {
+    match layout {
+      IppChannelLayout::C1  => ippiMalloc_16u_C1(width, height, pitch),
+      IppChannelLayout::C3  => ippiMalloc_16u_C3(width, height, pitch),
+      IppChannelLayout::C4  => ippiMalloc_16u_C4(width, height, pitch),
+      IppChannelLayout::AC4 => ippiMalloc_16u_AC4(width, height, pitch),
+    }
+  }
+
+  unsafe fn ipp_copy(layout: IppChannelLayout, src: *const u16, src_step: c_int, dst: *mut u16, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiCopy_16u_C1R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiCopy_16u_C3R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiCopy_16u_C4R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiCopy_16u_AC4R(src, src_step, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_set(layout: IppChannelLayout, value: *const u16, dst: *mut u16, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiSet_16u_C1R(*value, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiSet_16u_C3R(value, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiSet_16u_C4R(value, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiSet_16u_AC4R(value, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_mirror(layout: IppChannelLayout, src: *const u16, src_step: c_int, dst: *mut u16, dst_step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_16u_C1R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_16u_C3R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_16u_C4R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_16u_AC4R(src, src_step, dst, dst_step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_mirror_inplace(layout: IppChannelLayout, src_dst: *mut u16, step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_16u_C1IR(src_dst, step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_16u_C3IR(src_dst, step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_16u_C4IR(src_dst, step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_16u_AC4IR(src_dst, step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_transpose(layout: IppChannelLayout, src: *const u16, src_step: c_int, dst: *mut u16, dst_step: c_int, src_size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_16u_C1R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C3  => ippiTranspose_16u_C3R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C4  => ippiTranspose_16u_C4R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::AC4 => host_transpose(4, 3, src, src_step, dst, dst_step, src_size),
+    }
+  }
+
+  unsafe fn ipp_transpose_inplace(layout: IppChannelLayout, src_dst: *mut u16, step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_16u_C1IR(src_dst, step, size),
+      IppChannelLayout::C3  => ippiTranspose_16u_C3IR(src_dst, step, size),
+      IppChannelLayout::C4  => ippiTranspose_16u_C4IR(src_dst, step, size),
+      IppChannelLayout::AC4 => host_transpose_inplace(4, 3, src_dst, step, size),
+    }
+  }
+
+  unsafe fn ipp_resize_get_size(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetSize_16u(src_size, dst_size, interpolation, antialiasing, spec_size, init_buf_size)
+  }
+
+  unsafe fn ipp_resize_nearest_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeNearestInit_16u(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_linear_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeLinearInit_16u(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_cubic_init(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeCubicInit_16u(src_size, dst_size, value_b, value_c, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_lanczos_init(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeLanczosInit_16u(src_size, dst_size, num_lobes, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_super_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeSuperInit_16u(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_get_buffer_size(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetBufferSize_16u(spec, dst_size, num_channels, buf_size)
+  }
+
+  unsafe fn ipp_resize_get_src_offset(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus {
+    ippiResizeGetSrcOffset_16u(spec, dst_offset, src_offset)
+  }
+
+  unsafe fn ipp_resize_get_border_size(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus {
+    ippiResizeGetBorderSize_16u(spec, border_size)
+  }
+
+  unsafe fn ipp_resize(
+      kind: IppImageResizeKind, layout: IppChannelLayout,
+      src: *const u16, src_step: c_int, dst: *mut u16, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const u16,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppImageResizeKind::Nearest, IppChannelLayout::C1)     => ippiResizeNearest_16u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C3)     => ippiResizeNearest_16u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C4)     => ippiResizeNearest_16u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C1)       => ippiResizeSuper_16u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C3)       => ippiResizeSuper_16u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C4)       => ippiResizeSuper_16u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C1)      => ippiResizeLinear_16u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C3)      => ippiResizeLinear_16u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C4)      => ippiResizeLinear_16u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C1)   => ippiResizeCubic_16u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C3)   => ippiResizeCubic_16u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C4)   => ippiResizeCubic_16u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C1) => ippiResizeLanczos_16u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C3) => ippiResizeLanczos_16u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C4) => ippiResizeLanczos_16u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_resize_antialiasing(
+      _layout: IppChannelLayout,
+      _src: *const u16, _src_step: c_int, _dst: *mut u16, _dst_step: c_int,
+      _dst_offset: IppiPoint, _dst_size: IppiSize,
+      _border: IppiBorderType, _border_value: *const u16,
+      _spec: *const IppiResizeSpec_32f, _buf: *mut u8) -> IppStatus
+  {
+    IppStatus::IppStsNotSupportedModeErr
+  }
+}
+
+impl IppPixel for i16 {
+  unsafe fn ipp_malloc(layout: IppChannelLayout, width: c_int, height: c_int, pitch: *mut c_int) -> *mut i16 {
+    match layout {
+      IppChannelLayout::C1  => ippiMalloc_16s_C1(width, height, pitch),
+      IppChannelLayout::C3  => ippiMalloc_16s_C3(width, height, pitch),
+      IppChannelLayout::C4  => ippiMalloc_16s_C4(width, height, pitch),
+      IppChannelLayout::AC4 => ippiMalloc_16s_AC4(width, height, pitch),
+    }
+  }
+
+  unsafe fn ipp_copy(layout: IppChannelLayout, src: *const i16, src_step: c_int, dst: *mut i16, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiCopy_16s_C1R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiCopy_16s_C3R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiCopy_16s_C4R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiCopy_16s_AC4R(src, src_step, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_set(layout: IppChannelLayout, value: *const i16, dst: *mut i16, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiSet_16s_C1R(*value, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiSet_16s_C3R(value, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiSet_16s_C4R(value, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiSet_16s_AC4R(value, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_mirror(layout: IppChannelLayout, src: *const i16, src_step: c_int, dst: *mut i16, dst_step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_16s_C1R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_16s_C3R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_16s_C4R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_16s_AC4R(src, src_step, dst, dst_step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_mirror_inplace(layout: IppChannelLayout, src_dst: *mut i16, step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_16s_C1IR(src_dst, step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_16s_C3IR(src_dst, step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_16s_C4IR(src_dst, step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_16s_AC4IR(src_dst, step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_transpose(layout: IppChannelLayout, src: *const i16, src_step: c_int, dst: *mut i16, dst_step: c_int, src_size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_16s_C1R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C3  => ippiTranspose_16s_C3R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C4  => ippiTranspose_16s_C4R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::AC4 => host_transpose(4, 3, src, src_step, dst, dst_step, src_size),
+    }
+  }
+
+  unsafe fn ipp_transpose_inplace(layout: IppChannelLayout, src_dst: *mut i16, step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_16s_C1IR(src_dst, step, size),
+      IppChannelLayout::C3  => ippiTranspose_16s_C3IR(src_dst, step, size),
+      IppChannelLayout::C4  => ippiTranspose_16s_C4IR(src_dst, step, size),
+      IppChannelLayout::AC4 => host_transpose_inplace(4, 3, src_dst, step, size),
+    }
+  }
+
+  unsafe fn ipp_resize_get_size(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetSize_16s(src_size, dst_size, interpolation, antialiasing, spec_size, init_buf_size)
+  }
+
+  unsafe fn ipp_resize_nearest_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeNearestInit_16s(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_linear_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeLinearInit_16s(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_cubic_init(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeCubicInit_16s(src_size, dst_size, value_b, value_c, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_lanczos_init(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeLanczosInit_16s(src_size, dst_size, num_lobes, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_super_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeSuperInit_16s(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_get_buffer_size(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetBufferSize_16s(spec, dst_size, num_channels, buf_size)
+  }
+
+  unsafe fn ipp_resize_get_src_offset(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus {
+    ippiResizeGetSrcOffset_16s(spec, dst_offset, src_offset)
+  }
+
+  unsafe fn ipp_resize_get_border_size(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus {
+    ippiResizeGetBorderSize_16s(spec, border_size)
+  }
+
+  unsafe fn ipp_resize(
+      kind: IppImageResizeKind, layout: IppChannelLayout,
+      src: *const i16, src_step: c_int, dst: *mut i16, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const i16,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppImageResizeKind::Nearest, IppChannelLayout::C1)     => ippiResizeNearest_16s_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C3)     => ippiResizeNearest_16s_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C4)     => ippiResizeNearest_16s_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C1)       => ippiResizeSuper_16s_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C3)       => ippiResizeSuper_16s_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C4)       => ippiResizeSuper_16s_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C1)      => ippiResizeLinear_16s_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C3)      => ippiResizeLinear_16s_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C4)      => ippiResizeLinear_16s_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C1)   => ippiResizeCubic_16s_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C3)   => ippiResizeCubic_16s_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C4)   => ippiResizeCubic_16s_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C1) => ippiResizeLanczos_16s_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C3) => ippiResizeLanczos_16s_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C4) => ippiResizeLanczos_16s_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_resize_antialiasing(
+      _layout: IppChannelLayout,
+      _src: *const i16, _src_step: c_int, _dst: *mut i16, _dst_step: c_int,
+      _dst_offset: IppiPoint, _dst_size: IppiSize,
+      _border: IppiBorderType, _border_value: *const i16,
+      _spec: *const IppiResizeSpec_32f, _buf: *mut u8) -> IppStatus
+  {
+    IppStatus::IppStsNotSupportedModeErr
+  }
+}
+
+impl IppPixel for f32 {
+  fn supports_antialiasing() -> bool {
+    true
+  }
+
+  unsafe fn ipp_malloc(layout: IppChannelLayout, width: c_int, height: c_int, pitch: *mut c_int) -> *mut f32 {
+    match layout {
+      IppChannelLayout::C1  => ippiMalloc_32f_C1(width, height, pitch),
+      IppChannelLayout::C3  => ippiMalloc_32f_C3(width, height, pitch),
+      IppChannelLayout::C4  => ippiMalloc_32f_C4(width, height, pitch),
+      IppChannelLayout::AC4 => ippiMalloc_32f_AC4(width, height, pitch),
+    }
+  }
+
+  unsafe fn ipp_copy(layout: IppChannelLayout, src: *const f32, src_step: c_int, dst: *mut f32, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiCopy_32f_C1R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiCopy_32f_C3R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiCopy_32f_C4R(src, src_step, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiCopy_32f_AC4R(src, src_step, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_set(layout: IppChannelLayout, value: *const f32, dst: *mut f32, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiSet_32f_C1R(*value, dst, dst_step, size),
+      IppChannelLayout::C3  => ippiSet_32f_C3R(value, dst, dst_step, size),
+      IppChannelLayout::C4  => ippiSet_32f_C4R(value, dst, dst_step, size),
+      IppChannelLayout::AC4 => ippiSet_32f_AC4R(value, dst, dst_step, size),
+    }
+  }
+
+  unsafe fn ipp_mirror(layout: IppChannelLayout, src: *const f32, src_step: c_int, dst: *mut f32, dst_step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_32f_C1R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_32f_C3R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_32f_C4R(src, src_step, dst, dst_step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_32f_AC4R(src, src_step, dst, dst_step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_mirror_inplace(layout: IppChannelLayout, src_dst: *mut f32, step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_32f_C1IR(src_dst, step, size, flip),
+      IppChannelLayout::C3  => ippiMirror_32f_C3IR(src_dst, step, size, flip),
+      IppChannelLayout::C4  => ippiMirror_32f_C4IR(src_dst, step, size, flip),
+      IppChannelLayout::AC4 => ippiMirror_32f_AC4IR(src_dst, step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_transpose(layout: IppChannelLayout, src: *const f32, src_step: c_int, dst: *mut f32, dst_step: c_int, src_size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_32f_C1R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C3  => ippiTranspose_32f_C3R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::C4  => ippiTranspose_32f_C4R(src, src_step, dst, dst_step, src_size),
+      IppChannelLayout::AC4 => host_transpose(4, 3, src, src_step, dst, dst_step, src_size),
+    }
+  }
+
+  unsafe fn ipp_transpose_inplace(layout: IppChannelLayout, src_dst: *mut f32, step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_32f_C1IR(src_dst, step, size),
+      IppChannelLayout::C3  => ippiTranspose_32f_C3IR(src_dst, step, size),
+      IppChannelLayout::C4  => ippiTranspose_32f_C4IR(src_dst, step, size),
+      IppChannelLayout::AC4 => host_transpose_inplace(4, 3, src_dst, step, size),
+    }
+  }
+
+  unsafe fn ipp_resize_get_size(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetSize_32f(src_size, dst_size, interpolation, antialiasing, spec_size, init_buf_size)
+  }
+
+  unsafe fn ipp_resize_nearest_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeNearestInit_32f(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_linear_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeLinearInit_32f(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_cubic_init(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeCubicInit_32f(src_size, dst_size, value_b, value_c, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_lanczos_init(src_size: IppiSize, dst_size: IppiSize, num_lobes: u32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeLanczosInit_32f(src_size, dst_size, num_lobes, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_super_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeSuperInit_32f(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_get_buffer_size(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetBufferSize_32f(spec, dst_size, num_channels, buf_size)
+  }
+
+  unsafe fn ipp_resize_get_src_offset(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus {
+    ippiResizeGetSrcOffset_32f(spec, dst_offset, src_offset)
+  }
+
+  unsafe fn ipp_resize_get_border_size(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus {
+    ippiResizeGetBorderSize_32f(spec, border_size)
+  }
+
+  unsafe fn ipp_resize(
+      kind: IppImageResizeKind, layout: IppChannelLayout,
+      src: *const f32, src_step: c_int, dst: *mut f32, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const f32,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppImageResizeKind::Nearest, IppChannelLayout::C1)     => ippiResizeNearest_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C3)     => ippiResizeNearest_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Nearest, IppChannelLayout::C4)     => ippiResizeNearest_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C1)       => ippiResizeSuper_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C3)       => ippiResizeSuper_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Super, IppChannelLayout::C4)       => ippiResizeSuper_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C1)      => ippiResizeLinear_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C3)      => ippiResizeLinear_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C4)      => ippiResizeLinear_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C1)   => ippiResizeCubic_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C3)   => ippiResizeCubic_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C4)   => ippiResizeCubic_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C1) => ippiResizeLanczos_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C3) => ippiResizeLanczos_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Lanczos{..}, IppChannelLayout::C4) => ippiResizeLanczos_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_resize_antialiasing(
+      layout: IppChannelLayout,
+      src: *const f32, src_step: c_int, dst: *mut f32, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const f32,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus
+  {
+    match layout {
+      IppChannelLayout::C1  => ippiResizeAntialiasing_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      IppChannelLayout::C3  => ippiResizeAntialiasing_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      IppChannelLayout::C4  => ippiResizeAntialiasing_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+}
+
+impl IppPixel for f64 {
+  fn supports_layout(layout: IppChannelLayout) -> bool {
+    layout != IppChannelLayout::AC4
+  }
+
+  fn supports_resize(kind: IppImageResizeKind) -> bool {
+    !matches!(kind,
+        IppImageResizeKind::Nearest       |
+        IppImageResizeKind::Super         |
+        IppImageResizeKind::Lanczos{..})
+  }
+
+  unsafe fn ipp_malloc(layout: IppChannelLayout, width: c_int, height: c_int, pitch: *mut c_int) -> *mut f64 {
+    match layout {
+      IppChannelLayout::C1  => ippiMalloc_64f_C1(width, height, pitch),
+      IppChannelLayout::C3  => ippiMalloc_64f_C3(width, height, pitch),
+      IppChannelLayout::C4  => ippiMalloc_64f_C4(width, height, pitch),
+      IppChannelLayout::AC4 => null_mut(),
+    }
+  }
+
+  // IPP has no 64f copy primitives, so double images are copied bytewise.
+  unsafe fn ipp_copy(layout: IppChannelLayout, src: *const f64, src_step: c_int, dst: *mut f64, dst_step: c_int, size: IppiSize) -> IppStatus {
+    let byte_size = IppiSize{
+      width:  size.width * (layout.num_channels() * size_of::<f64>()) as c_int,
+      height: size.height,
+    };
+    match layout {
+      IppChannelLayout::AC4 => IppStatus::IppStsNotSupportedModeErr,
+      _ => ippiCopy_8u_C1R(src as *const u8, src_step, dst as *mut u8, dst_step, byte_size),
+    }
+  }
+
+  // An f64 pixel has the size of a 16u C4 pixel, so single channel images
+  // are filled as such.
+  unsafe fn ipp_set(layout: IppChannelLayout, value: *const f64, dst: *mut f64, dst_step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => {
+        let words = transmute::<f64, [u16; 4]>(*value);
+        ippiSet_16u_C4R(words.as_ptr(), dst as *mut u16, dst_step, size)
+      }
+      IppChannelLayout::AC4 => IppStatus::IppStsNotSupportedModeErr,
+      _ => host_set(layout.num_channels(), layout.num_channels(), value, dst, dst_step, size),
+    }
+  }
+
+  // An f64 pixel has the size of a 16u C4 pixel, so single channel images
+  // are moved as such; there are no 64-bit kernels for more channels.
+  unsafe fn ipp_mirror(layout: IppChannelLayout, src: *const f64, src_step: c_int, dst: *mut f64, dst_step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_16u_C4R(src as *const u16, src_step, dst as *mut u16, dst_step, size, flip),
+      IppChannelLayout::AC4 => IppStatus::IppStsNotSupportedModeErr,
+      _ => host_mirror(layout.num_channels(), layout.num_channels(), src, src_step, dst, dst_step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_mirror_inplace(layout: IppChannelLayout, src_dst: *mut f64, step: c_int, size: IppiSize, flip: IppiAxis) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiMirror_16u_C4IR(src_dst as *mut u16, step, size, flip),
+      IppChannelLayout::AC4 => IppStatus::IppStsNotSupportedModeErr,
+      _ => host_mirror_inplace(layout.num_channels(), layout.num_channels(), src_dst, step, size, flip),
+    }
+  }
+
+  unsafe fn ipp_transpose(layout: IppChannelLayout, src: *const f64, src_step: c_int, dst: *mut f64, dst_step: c_int, src_size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_16u_C4R(src as *const u16, src_step, dst as *mut u16, dst_step, src_size),
+      IppChannelLayout::AC4 => IppStatus::IppStsNotSupportedModeErr,
+      _ => host_transpose(layout.num_channels(), layout.num_channels(), src, src_step, dst, dst_step, src_size),
+    }
+  }
+
+  unsafe fn ipp_transpose_inplace(layout: IppChannelLayout, src_dst: *mut f64, step: c_int, size: IppiSize) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiTranspose_16u_C4IR(src_dst as *mut u16, step, size),
+      IppChannelLayout::AC4 => IppStatus::IppStsNotSupportedModeErr,
+      _ => host_transpose_inplace(layout.num_channels(), layout.num_channels(), src_dst, step, size),
+    }
+  }
+
+  unsafe fn ipp_resize_get_size(src_size: IppiSize, dst_size: IppiSize, interpolation: IppiInterpolationType, antialiasing: u32, spec_size: *mut c_int, init_buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetSize_64f(src_size, dst_size, interpolation, antialiasing, spec_size, init_buf_size)
+  }
+
+  unsafe fn ipp_resize_nearest_init(_src_size: IppiSize, _dst_size: IppiSize, _spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    IppStatus::IppStsNotSupportedModeErr
+  }
+
+  unsafe fn ipp_resize_linear_init(src_size: IppiSize, dst_size: IppiSize, spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    ippiResizeLinearInit_64f(src_size, dst_size, spec)
+  }
+
+  unsafe fn ipp_resize_cubic_init(src_size: IppiSize, dst_size: IppiSize, value_b: f32, value_c: f32, spec: *mut IppiResizeSpec_32f, init_buf: *mut u8) -> IppStatus {
+    ippiResizeCubicInit_64f(src_size, dst_size, value_b as f64, value_c as f64, spec, init_buf)
+  }
+
+  unsafe fn ipp_resize_lanczos_init(_src_size: IppiSize, _dst_size: IppiSize, _num_lobes: u32, _spec: *mut IppiResizeSpec_32f, _init_buf: *mut u8) -> IppStatus {
+    IppStatus::IppStsNotSupportedModeErr
+  }
+
+  unsafe fn ipp_resize_super_init(_src_size: IppiSize, _dst_size: IppiSize, _spec: *mut IppiResizeSpec_32f) -> IppStatus {
+    IppStatus::IppStsNotSupportedModeErr
+  }
+
+  unsafe fn ipp_resize_get_buffer_size(spec: *const IppiResizeSpec_32f, dst_size: IppiSize, num_channels: u32, buf_size: *mut c_int) -> IppStatus {
+    ippiResizeGetBufferSize_64f(spec, dst_size, num_channels, buf_size)
+  }
+
+  unsafe fn ipp_resize_get_src_offset(spec: *const IppiResizeSpec_32f, dst_offset: IppiPoint, src_offset: *mut IppiPoint) -> IppStatus {
+    ippiResizeGetSrcOffset_64f(spec, dst_offset, src_offset)
+  }
+
+  unsafe fn ipp_resize_get_border_size(spec: *const IppiResizeSpec_32f, border_size: *mut IppiBorderSize) -> IppStatus {
+    ippiResizeGetBorderSize_64f(spec, border_size)
+  }
+
+  unsafe fn ipp_resize(
+      kind: IppImageResizeKind, layout: IppChannelLayout,
+      src: *const f64, src_step: c_int, dst: *mut f64, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      border: IppiBorderType, border_value: *const f64,
+      spec: *const IppiResizeSpec_32f, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppImageResizeKind::Linear, IppChannelLayout::C1)      => ippiResizeLinear_64f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C3)      => ippiResizeLinear_64f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Linear, IppChannelLayout::C4)      => ippiResizeLinear_64f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C1)   => ippiResizeCubic_64f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C3)   => ippiResizeCubic_64f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      (IppImageResizeKind::Cubic{..}, IppChannelLayout::C4)   => ippiResizeCubic_64f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, border, border_value, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_resize_antialiasing(
+      _layout: IppChannelLayout,
+      _src: *const f64, _src_step: c_int, _dst: *mut f64, _dst_step: c_int,
+      _dst_offset: IppiPoint, _dst_size: IppiSize,
+      _border: IppiBorderType, _border_value: *const f64,
+      _spec: *const IppiResizeSpec_32f, _buf: *mut u8) -> IppStatus
+  {
+    IppStatus::IppStsNotSupportedModeErr
+  }
+}
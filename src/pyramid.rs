@@ -0,0 +1,545 @@
+use ffi::*;
+use {IppBuf, IppChannelLayout, IppError, IppImageBuf, IppImageBufExt, IppPixel, IppResult, ipp_check, ipp_size};
+use view::{IppImageView, IppImageViewMut, ipp_try_copy_image};
+
+use libc::{c_int};
+
+use std::marker::{PhantomData};
+use std::ptr::{null_mut};
+
+/// Pixel depths for which IPP provides Gaussian pyramid layers.
+pub trait IppPyramidPixel: IppPixel {
+  /// Element type of pyramid kernels. IPP normalizes kernels by their sum.
+  type Kernel: Copy;
+
+  /// The 5-tap binomial kernel `[1, 4, 6, 4, 1] / 16`.
+  fn binomial_kernel() -> [Self::Kernel; 5];
+
+  /// # Safety
+  ///
+  /// `state_size` must be valid for writes.
+  unsafe fn ipp_pyramid_down_get_size(layout: IppChannelLayout, src_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  /// # Safety
+  ///
+  /// `kernel` must point to `ker_size` elements, `state_buf` to at least the
+  /// state size reported by `ipp_pyramid_down_get_size` for the same
+  /// arguments, and `state` must be valid for writes.
+  unsafe fn ipp_pyramid_down_init(layout: IppChannelLayout, state: *mut *mut IppiPyramidDownState, src_roi: IppiSize, rate: f32, kernel: *const Self::Kernel, ker_size: c_int, state_buf: *mut u8) -> IppStatus;
+  /// # Safety
+  ///
+  /// `state` must come from `ipp_pyramid_down_init` for `src_roi` with its
+  /// state buffer still alive. `src` must be an image of `src_roi` and `dst`
+  /// one of `dst_roi`, both of `layout` as described on `IppPixel`.
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn ipp_pyramid_down(layout: IppChannelLayout, src: *const Self, src_step: c_int, src_roi: IppiSize, dst: *mut Self, dst_step: c_int, dst_roi: IppiSize, state: *mut IppiPyramidDownState) -> IppStatus;
+  /// # Safety
+  ///
+  /// `state_size` must be valid for writes.
+  unsafe fn ipp_pyramid_up_get_size(layout: IppChannelLayout, dst_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus;
+  /// # Safety
+  ///
+  /// As for `ipp_pyramid_down_init`, with the size reported by
+  /// `ipp_pyramid_up_get_size`.
+  unsafe fn ipp_pyramid_up_init(layout: IppChannelLayout, state: *mut *mut IppiPyramidUpState, dst_roi: IppiSize, rate: f32, kernel: *const Self::Kernel, ker_size: c_int, state_buf: *mut u8) -> IppStatus;
+  /// # Safety
+  ///
+  /// As for `ipp_pyramid_down`, with a state from `ipp_pyramid_up_init` for
+  /// `dst_roi`.
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn ipp_pyramid_up(layout: IppChannelLayout, src: *const Self, src_step: c_int, src_roi: IppiSize, dst: *mut Self, dst_step: c_int, dst_roi: IppiSize, state: *mut IppiPyramidUpState) -> IppStatus;
+}
+
+impl IppPyramidPixel for u8 {
+  type Kernel = i16;
+
+  fn binomial_kernel() -> [i16; 5] {
+    [1, 4, 6, 4, 1]
+  }
+
+  unsafe fn ipp_pyramid_down_get_size(layout: IppChannelLayout, src_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerDownGetSize_8u_C1R(src_roi, rate, ker_size, state_size),
+      IppChannelLayout::C3  => ippiPyramidLayerDownGetSize_8u_C3R(src_roi, rate, ker_size, state_size),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_down_init(layout: IppChannelLayout, state: *mut *mut IppiPyramidDownState, src_roi: IppiSize, rate: f32, kernel: *const i16, ker_size: c_int, state_buf: *mut u8) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerDownInit_8u_C1R(state, src_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      IppChannelLayout::C3  => ippiPyramidLayerDownInit_8u_C3R(state, src_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_down(layout: IppChannelLayout, src: *const u8, src_step: c_int, src_roi: IppiSize, dst: *mut u8, dst_step: c_int, dst_roi: IppiSize, state: *mut IppiPyramidDownState) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerDown_8u_C1R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      IppChannelLayout::C3  => ippiPyramidLayerDown_8u_C3R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_up_get_size(layout: IppChannelLayout, dst_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerUpGetSize_8u_C1R(dst_roi, rate, ker_size, state_size),
+      IppChannelLayout::C3  => ippiPyramidLayerUpGetSize_8u_C3R(dst_roi, rate, ker_size, state_size),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_up_init(layout: IppChannelLayout, state: *mut *mut IppiPyramidUpState, dst_roi: IppiSize, rate: f32, kernel: *const i16, ker_size: c_int, state_buf: *mut u8) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerUpInit_8u_C1R(state, dst_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      IppChannelLayout::C3  => ippiPyramidLayerUpInit_8u_C3R(state, dst_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_up(layout: IppChannelLayout, src: *const u8, src_step: c_int, src_roi: IppiSize, dst: *mut u8, dst_step: c_int, dst_roi: IppiSize, state: *mut IppiPyramidUpState) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerUp_8u_C1R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      IppChannelLayout::C3  => ippiPyramidLayerUp_8u_C3R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+}
+
+impl IppPyramidPixel for f32 {
+  type Kernel = f32;
+
+  fn binomial_kernel() -> [f32; 5] {
+    [0.0625, 0.25, 0.375, 0.25, 0.0625]
+  }
+
+  unsafe fn ipp_pyramid_down_get_size(layout: IppChannelLayout, src_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerDownGetSize_32f_C1R(src_roi, rate, ker_size, state_size),
+      IppChannelLayout::C3  => ippiPyramidLayerDownGetSize_32f_C3R(src_roi, rate, ker_size, state_size),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_down_init(layout: IppChannelLayout, state: *mut *mut IppiPyramidDownState, src_roi: IppiSize, rate: f32, kernel: *const f32, ker_size: c_int, state_buf: *mut u8) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerDownInit_32f_C1R(state, src_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      IppChannelLayout::C3  => ippiPyramidLayerDownInit_32f_C3R(state, src_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_down(layout: IppChannelLayout, src: *const f32, src_step: c_int, src_roi: IppiSize, dst: *mut f32, dst_step: c_int, dst_roi: IppiSize, state: *mut IppiPyramidDownState) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerDown_32f_C1R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      IppChannelLayout::C3  => ippiPyramidLayerDown_32f_C3R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_up_get_size(layout: IppChannelLayout, dst_roi: IppiSize, rate: f32, ker_size: c_int, state_size: *mut c_int) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerUpGetSize_32f_C1R(dst_roi, rate, ker_size, state_size),
+      IppChannelLayout::C3  => ippiPyramidLayerUpGetSize_32f_C3R(dst_roi, rate, ker_size, state_size),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_up_init(layout: IppChannelLayout, state: *mut *mut IppiPyramidUpState, dst_roi: IppiSize, rate: f32, kernel: *const f32, ker_size: c_int, state_buf: *mut u8) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerUpInit_32f_C1R(state, dst_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      IppChannelLayout::C3  => ippiPyramidLayerUpInit_32f_C3R(state, dst_roi, rate, kernel, ker_size, IPPI_INTER_LINEAR, state_buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_pyramid_up(layout: IppChannelLayout, src: *const f32, src_step: c_int, src_roi: IppiSize, dst: *mut f32, dst_step: c_int, dst_roi: IppiSize, state: *mut IppiPyramidUpState) -> IppStatus {
+    match layout {
+      IppChannelLayout::C1  => ippiPyramidLayerUp_32f_C1R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      IppChannelLayout::C3  => ippiPyramidLayerUp_32f_C3R(src, src_step, src_roi, dst, dst_step, dst_roi, state),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+}
+
+fn check_pyramid_args<K>(layout: IppChannelLayout, rate: f32, kernel: &[K]) -> IppResult<()> {
+  match layout {
+    IppChannelLayout::C1 | IppChannelLayout::C3 => {}
+    _ => return Err(IppError::Unsupported("pyramid: only C1 and C3 layouts are supported")),
+  }
+  if !(rate > 1.0 && rate <= 10.0) {
+    return Err(IppError::InvalidArg("pyramid: rate must be in (1, 10]"));
+  }
+  if kernel.len().is_multiple_of(2) {
+    return Err(IppError::InvalidArg("pyramid: kernel length must be odd"));
+  }
+  Ok(())
+}
+
+fn check_layer_views<T>(layout: IppChannelLayout, src_size: (usize, usize), dst_size: (usize, usize), src: &IppImageView<T>, dst: &IppImageViewMut<T>) -> IppResult<()> where T: Copy {
+  if !(src_size.0 <= src.width() && src_size.1 <= src.height()) {
+    return Err(IppError::OutOfBounds);
+  }
+  if !(dst_size.0 <= dst.width() && dst_size.1 <= dst.height()) {
+    return Err(IppError::OutOfBounds);
+  }
+  if src.layout() != layout || dst.layout() != layout {
+    return Err(IppError::InvalidArg("pyramid: image layout does not match pyramid layout"));
+  }
+  Ok(())
+}
+
+/// Blurs and downsamples one pyramid level by `rate`.
+pub struct IppPyramidLayerDown<T> where T: Copy {
+  // `state` points into `_state_buf`.
+  _state_buf:   IppBuf<u8>,
+  state:        *mut IppiPyramidDownState,
+  lay:  IppChannelLayout,
+  src:  (usize, usize),
+  dst:  (usize, usize),
+  _mrk: PhantomData<fn (T)>,
+}
+
+// The state is owned and only used through `&mut self`.
+unsafe impl<T> Send for IppPyramidLayerDown<T> where T: Copy {}
+
+impl<T> IppPyramidLayerDown<T> where T: IppPyramidPixel {
+  pub fn try_new(layout: IppChannelLayout, src_width: usize, src_height: usize, rate: f32, kernel: &[T::Kernel]) -> IppResult<IppPyramidLayerDown<T>> {
+    check_pyramid_args(layout, rate, kernel)?;
+    let src_roi = ipp_size(src_width, src_height)?;
+    let mut dst_roi = IppiSize{width: 0, height: 0};
+    let status = unsafe { ippiGetPyramidDownROI(src_roi, &mut dst_roi as *mut _, rate) };
+    ipp_check(status)?;
+    let mut state_size = 0;
+    let status = unsafe { T::ipp_pyramid_down_get_size(layout, src_roi, rate, kernel.len() as _, &mut state_size as *mut _) };
+    ipp_check(status)?;
+    let mut state_buf = IppBuf::<u8>::try_alloc(state_size as _)?;
+    let mut state = null_mut();
+    let status = unsafe { T::ipp_pyramid_down_init(
+        layout,
+        &mut state as *mut _,
+        src_roi,
+        rate,
+        kernel.as_ptr(),
+        kernel.len() as _,
+        state_buf.as_mut_ptr(),
+    ) };
+    ipp_check(status)?;
+    Ok(IppPyramidLayerDown{
+      _state_buf:   state_buf,
+      state:        state,
+      lay:  layout,
+      src:  (src_width, src_height),
+      dst:  (dst_roi.width as _, dst_roi.height as _),
+      _mrk: PhantomData,
+    })
+  }
+
+  pub fn src_size(&self) -> (usize, usize) {
+    self.src
+  }
+
+  pub fn dst_size(&self) -> (usize, usize) {
+    self.dst
+  }
+
+  pub fn apply(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_apply(src, dst).unwrap();
+  }
+
+  pub fn try_apply(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    check_layer_views(self.lay, self.src, self.dst, src, dst)?;
+    let status = unsafe { T::ipp_pyramid_down(
+        self.lay,
+        src.as_ptr(),
+        src.pitch() as _,
+        IppiSize{width: self.src.0 as _, height: self.src.1 as _},
+        dst.as_mut_ptr(),
+        dst.pitch() as _,
+        IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
+        self.state,
+    ) };
+    ipp_check(status)?;
+    Ok(())
+  }
+}
+
+/// Upsamples one pyramid level by `rate` and blurs it.
+pub struct IppPyramidLayerUp<T> where T: Copy {
+  // `state` points into `_state_buf`.
+  _state_buf:   IppBuf<u8>,
+  state:        *mut IppiPyramidUpState,
+  lay:  IppChannelLayout,
+  src:  (usize, usize),
+  dst:  (usize, usize),
+  _mrk: PhantomData<fn (T)>,
+}
+
+// The state is owned and only used through `&mut self`.
+unsafe impl<T> Send for IppPyramidLayerUp<T> where T: Copy {}
+
+impl<T> IppPyramidLayerUp<T> where T: IppPyramidPixel {
+  /// The dst size must lie within the range IPP allows for upsampling the
+  /// src size by `rate`; the size of the level the src was downsampled from
+  /// always does.
+  pub fn try_new(layout: IppChannelLayout, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize, rate: f32, kernel: &[T::Kernel]) -> IppResult<IppPyramidLayerUp<T>> {
+    check_pyramid_args(layout, rate, kernel)?;
+    let src_roi = ipp_size(src_width, src_height)?;
+    let dst_roi = ipp_size(dst_width, dst_height)?;
+    let mut dst_roi_min = IppiSize{width: 0, height: 0};
+    let mut dst_roi_max = IppiSize{width: 0, height: 0};
+    let status = unsafe { ippiGetPyramidUpROI(src_roi, &mut dst_roi_min as *mut _, &mut dst_roi_max as *mut _, rate) };
+    ipp_check(status)?;
+    if !(dst_roi_min.width <= dst_roi.width && dst_roi.width <= dst_roi_max.width
+        && dst_roi_min.height <= dst_roi.height && dst_roi.height <= dst_roi_max.height)
+    {
+      return Err(IppError::InvalidSize{width: dst_width, height: dst_height});
+    }
+    let mut state_size = 0;
+    let status = unsafe { T::ipp_pyramid_up_get_size(layout, dst_roi, rate, kernel.len() as _, &mut state_size as *mut _) };
+    ipp_check(status)?;
+    let mut state_buf = IppBuf::<u8>::try_alloc(state_size as _)?;
+    let mut state = null_mut();
+    let status = unsafe { T::ipp_pyramid_up_init(
+        layout,
+        &mut state as *mut _,
+        dst_roi,
+        rate,
+        kernel.as_ptr(),
+        kernel.len() as _,
+        state_buf.as_mut_ptr(),
+    ) };
+    ipp_check(status)?;
+    Ok(IppPyramidLayerUp{
+      _state_buf:   state_buf,
+      state:        state,
+      lay:  layout,
+      src:  (src_width, src_height),
+      dst:  (dst_width, dst_height),
+      _mrk: PhantomData,
+    })
+  }
+
+  pub fn src_size(&self) -> (usize, usize) {
+    self.src
+  }
+
+  pub fn dst_size(&self) -> (usize, usize) {
+    self.dst
+  }
+
+  pub fn apply(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_apply(src, dst).unwrap();
+  }
+
+  pub fn try_apply(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    check_layer_views(self.lay, self.src, self.dst, src, dst)?;
+    let status = unsafe { T::ipp_pyramid_up(
+        self.lay,
+        src.as_ptr(),
+        src.pitch() as _,
+        IppiSize{width: self.src.0 as _, height: self.src.1 as _},
+        dst.as_mut_ptr(),
+        dst.pitch() as _,
+        IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
+        self.state,
+    ) };
+    ipp_check(status)?;
+    Ok(())
+  }
+}
+
+pub struct IppGaussianPyramid<T> where T: Copy {
+  levels:   Vec<IppImageBuf<T>>,
+  downs:    Vec<IppPyramidLayerDown<T>>,
+}
+
+impl<T> IppGaussianPyramid<T> where T: IppPyramidPixel {
+  pub fn new(layout: IppChannelLayout, width: usize, height: usize, num_levels: usize) -> IppGaussianPyramid<T> {
+    IppGaussianPyramid::try_new(layout, width, height, num_levels).unwrap()
+  }
+
+  /// Creates a pyramid which halves each level with the binomial kernel.
+  pub fn try_new(layout: IppChannelLayout, width: usize, height: usize, num_levels: usize) -> IppResult<IppGaussianPyramid<T>> {
+    IppGaussianPyramid::try_new_with_kernel(layout, width, height, num_levels, 2.0, &T::binomial_kernel())
+  }
+
+  /// Creates a pyramid of `num_levels` levels, including the full size level
+  /// 0, each of which is smaller than the previous by `rate`.
+  pub fn try_new_with_kernel(layout: IppChannelLayout, width: usize, height: usize, num_levels: usize, rate: f32, kernel: &[T::Kernel]) -> IppResult<IppGaussianPyramid<T>> {
+    if num_levels == 0 {
+      return Err(IppError::InvalidArg("pyramid: need at least one level"));
+    }
+    check_pyramid_args(layout, rate, kernel)?;
+    let mut levels = vec![];
+    let mut downs = vec![];
+    levels.push(IppImageBuf::<T>::try_alloc_with_layout(width, height, layout)?);
+    let mut prev_size = (width, height);
+    for _ in 1 .. num_levels {
+      let down = IppPyramidLayerDown::<T>::try_new(layout, prev_size.0, prev_size.1, rate, kernel)?;
+      let next_size = down.dst_size();
+      levels.push(IppImageBuf::<T>::try_alloc_with_layout(next_size.0, next_size.1, layout)?);
+      downs.push(down);
+      prev_size = next_size;
+    }
+    Ok(IppGaussianPyramid{
+      levels:   levels,
+      downs:    downs,
+    })
+  }
+
+  pub fn build(&mut self, src: &IppImageView<T>) {
+    self.try_build(src).unwrap();
+  }
+
+  /// Copies `src` into level 0 and computes the remaining levels.
+  pub fn try_build(&mut self, src: &IppImageView<T>) -> IppResult<()> {
+    ipp_try_copy_image(src, &mut self.levels[0].view_mut())?;
+    for k in 0 .. self.downs.len() {
+      let (prev_levels, next_levels) = self.levels.split_at_mut(k+1);
+      self.downs[k].try_apply(&prev_levels[k].view(), &mut next_levels[0].view_mut())?;
+    }
+    Ok(())
+  }
+
+  pub fn num_levels(&self) -> usize {
+    self.levels.len()
+  }
+
+  pub fn level(&self, k: usize) -> Option<IppImageView<'_, T>> {
+    self.levels.get(k).map(|buf| buf.view())
+  }
+
+  pub fn levels(&self) -> impl Iterator<Item=IppImageView<'_, T>> {
+    self.levels.iter().map(|buf| buf.view())
+  }
+}
+
+// `dst = src1 - src2`.
+fn ipp_sub_f32(src1: &IppImageView<f32>, src2: &IppImageView<f32>, dst: &mut IppImageViewMut<f32>) -> IppResult<()> {
+  let size = ipp_size(dst.width(), dst.height())?;
+  // ippiSub computes its second operand minus its first.
+  let status = unsafe { match dst.layout() {
+    IppChannelLayout::C1  => ippiSub_32f_C1R(src2.as_ptr(), src2.pitch() as _, src1.as_ptr(), src1.pitch() as _, dst.as_mut_ptr(), dst.pitch() as _, size),
+    IppChannelLayout::C3  => ippiSub_32f_C3R(src2.as_ptr(), src2.pitch() as _, src1.as_ptr(), src1.pitch() as _, dst.as_mut_ptr(), dst.pitch() as _, size),
+    _ => IppStatus::IppStsNotSupportedModeErr,
+  } };
+  ipp_check(status)?;
+  Ok(())
+}
+
+// `src_dst += src`.
+fn ipp_add_inplace_f32(src: &IppImageView<f32>, src_dst: &mut IppImageViewMut<f32>) -> IppResult<()> {
+  let size = ipp_size(src_dst.width(), src_dst.height())?;
+  let status = unsafe { match src_dst.layout() {
+    IppChannelLayout::C1  => ippiAdd_32f_C1IR(src.as_ptr(), src.pitch() as _, src_dst.as_mut_ptr(), src_dst.pitch() as _, size),
+    IppChannelLayout::C3  => ippiAdd_32f_C3IR(src.as_ptr(), src.pitch() as _, src_dst.as_mut_ptr(), src_dst.pitch() as _, size),
+    _ => IppStatus::IppStsNotSupportedModeErr,
+  } };
+  ipp_check(status)?;
+  Ok(())
+}
+
+/// A Laplacian pyramid: each level but the last holds the difference between
+/// a Gaussian level and the upsampled next Gaussian level, and the last level
+/// holds the smallest Gaussian level. Levels may be edited, e.g. blended,
+/// before `reconstruct`.
+pub struct IppLaplacianPyramid<T> where T: Copy {
+  gauss:    IppGaussianPyramid<T>,
+  ups:      Vec<IppPyramidLayerUp<T>>,
+  levels:   Vec<IppImageBuf<T>>,
+  tmps:     Vec<IppImageBuf<T>>,
+}
+
+impl IppLaplacianPyramid<f32> {
+  pub fn new(layout: IppChannelLayout, width: usize, height: usize, num_levels: usize) -> IppLaplacianPyramid<f32> {
+    IppLaplacianPyramid::try_new(layout, width, height, num_levels).unwrap()
+  }
+
+  pub fn try_new(layout: IppChannelLayout, width: usize, height: usize, num_levels: usize) -> IppResult<IppLaplacianPyramid<f32>> {
+    IppLaplacianPyramid::try_new_with_kernel(layout, width, height, num_levels, 2.0, &f32::binomial_kernel())
+  }
+
+  pub fn try_new_with_kernel(layout: IppChannelLayout, width: usize, height: usize, num_levels: usize, rate: f32, kernel: &[f32]) -> IppResult<IppLaplacianPyramid<f32>> {
+    let gauss = IppGaussianPyramid::<f32>::try_new_with_kernel(layout, width, height, num_levels, rate, kernel)?;
+    let mut ups = vec![];
+    let mut levels = vec![];
+    let mut tmps = vec![];
+    for k in 0 .. num_levels {
+      let level = gauss.level(k).unwrap();
+      let (level_width, level_height) = (level.width(), level.height());
+      levels.push(IppImageBuf::<f32>::try_alloc_with_layout(level_width, level_height, layout)?);
+      if k + 1 < num_levels {
+        let next = gauss.level(k + 1).unwrap();
+        ups.push(IppPyramidLayerUp::<f32>::try_new(layout, next.width(), next.height(), level_width, level_height, rate, kernel)?);
+        tmps.push(IppImageBuf::<f32>::try_alloc_with_layout(level_width, level_height, layout)?);
+      }
+    }
+    Ok(IppLaplacianPyramid{
+      gauss:    gauss,
+      ups:      ups,
+      levels:   levels,
+      tmps:     tmps,
+    })
+  }
+
+  pub fn build(&mut self, src: &IppImageView<f32>) {
+    self.try_build(src).unwrap();
+  }
+
+  pub fn try_build(&mut self, src: &IppImageView<f32>) -> IppResult<()> {
+    self.gauss.try_build(src)?;
+    let num_levels = self.levels.len();
+    for k in 0 .. num_levels - 1 {
+      let gauss_level = self.gauss.level(k).unwrap();
+      let gauss_next = self.gauss.level(k + 1).unwrap();
+      self.ups[k].try_apply(&gauss_next, &mut self.tmps[k].view_mut())?;
+      ipp_sub_f32(&gauss_level, &self.tmps[k].view(), &mut self.levels[k].view_mut())?;
+    }
+    let gauss_last = self.gauss.level(num_levels - 1).unwrap();
+    ipp_try_copy_image(&gauss_last, &mut self.levels[num_levels - 1].view_mut())
+  }
+
+  pub fn reconstruct(&mut self, dst: &mut IppImageViewMut<f32>) {
+    self.try_reconstruct(dst).unwrap();
+  }
+
+  /// Collapses the pyramid into a full size image.
+  pub fn try_reconstruct(&mut self, dst: &mut IppImageViewMut<f32>) -> IppResult<()> {
+    let num_levels = self.levels.len();
+    for k in (0 .. num_levels - 1).rev() {
+      let (tmps_lo, tmps_hi) = self.tmps.split_at_mut(k + 1);
+      let coarse = if k + 1 == num_levels - 1 {
+        self.levels[k + 1].view()
+      } else {
+        tmps_hi[0].view()
+      };
+      self.ups[k].try_apply(&coarse, &mut tmps_lo[k].view_mut())?;
+      ipp_add_inplace_f32(&self.levels[k].view(), &mut tmps_lo[k].view_mut())?;
+    }
+    if num_levels == 1 {
+      ipp_try_copy_image(&self.levels[0].view(), dst)
+    } else {
+      ipp_try_copy_image(&self.tmps[0].view(), dst)
+    }
+  }
+
+  pub fn gaussian(&self) -> &IppGaussianPyramid<f32> {
+    &self.gauss
+  }
+
+  pub fn num_levels(&self) -> usize {
+    self.levels.len()
+  }
+
+  pub fn level(&self, k: usize) -> Option<IppImageView<'_, f32>> {
+    self.levels.get(k).map(|buf| buf.view())
+  }
+
+  pub fn level_mut(&mut self, k: usize) -> Option<IppImageViewMut<'_, f32>> {
+    self.levels.get_mut(k).map(|buf| buf.view_mut())
+  }
+
+  pub fn levels(&self) -> impl Iterator<Item=IppImageView<'_, f32>> {
+    self.levels.iter().map(|buf| buf.view())
+  }
+}
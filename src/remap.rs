@@ -0,0 +1,222 @@
+use ffi::*;
+use {IppChannelLayout, IppError, IppImageBuf, IppImageBufExt, IppResult, ipp_check, ipp_size};
+use view::{IppImageView, IppImageViewMut};
+use warp::{IppWarpPixel};
+
+use libc::{c_int};
+
+use std::marker::{PhantomData};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IppRemapKind {
+  Nearest,
+  Linear,
+  Cubic,
+}
+
+impl IppRemapKind {
+  fn interpolation(&self) -> c_int {
+    match *self {
+      IppRemapKind::Nearest => IPPI_INTER_NN,
+      IppRemapKind::Linear  => IPPI_INTER_LINEAR,
+      IppRemapKind::Cubic   => IPPI_INTER_CUBIC,
+    }
+  }
+}
+
+/// Pinhole camera intrinsics, in pixels.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct IppCameraIntrinsics {
+  pub fx:       f64,
+  pub fy:       f64,
+  pub cx:       f64,
+  pub cy:       f64,
+}
+
+/// Radial-tangential (Brown-Conrady) lens distortion coefficients, as used
+/// by OpenCV: radial `k1`, `k2`, `k3` and tangential `p1`, `p2`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct IppRadialTangential {
+  pub k1:       f64,
+  pub k2:       f64,
+  pub k3:       f64,
+  pub p1:       f64,
+  pub p2:       f64,
+}
+
+impl IppRadialTangential {
+  /// Distorts the normalized image point (x, y).
+  pub fn distort(&self, x: f64, y: f64) -> (f64, f64) {
+    let r2 = x * x + y * y;
+    let radial = 1.0 + r2 * (self.k1 + r2 * (self.k2 + r2 * self.k3));
+    let xd = x * radial + 2.0 * self.p1 * x * y + self.p2 * (r2 + 2.0 * x * x);
+    let yd = y * radial + self.p1 * (r2 + 2.0 * y * y) + 2.0 * self.p2 * x * y;
+    (xd, yd)
+  }
+}
+
+/// Builds a pair of `width` x `height` coordinate maps, where `f(x, y)`
+/// gives the src coordinates sampled for dst pixel (x, y).
+pub fn ipp_build_remap<F>(width: usize, height: usize, mut f: F) -> IppResult<(IppImageBuf<f32>, IppImageBuf<f32>)> where F: FnMut(usize, usize) -> (f32, f32) {
+  let len = match width.checked_mul(height) {
+    Some(len) => len,
+    None => return Err(IppError::InvalidSize{width: width, height: height}),
+  };
+  let mut xs = Vec::with_capacity(len);
+  let mut ys = Vec::with_capacity(len);
+  for y in 0 .. height {
+    for x in 0 .. width {
+      let (sx, sy) = f(x, y);
+      xs.push(sx);
+      ys.push(sy);
+    }
+  }
+  let mut map_x = IppImageBuf::try_alloc(width, height)?;
+  let mut map_y = IppImageBuf::try_alloc(width, height)?;
+  map_x.try_write(&xs)?;
+  map_y.try_write(&ys)?;
+  Ok((map_x, map_y))
+}
+
+/// Builds the maps which undistort an image taken by `src_camera` with lens
+/// distortion `distortion`, producing a `width` x `height` image as seen by
+/// the distortion-free `dst_camera`. Passing the same intrinsics for both
+/// keeps the original focal length and principal point.
+pub fn ipp_undistort_maps(src_camera: IppCameraIntrinsics, distortion: IppRadialTangential, dst_camera: IppCameraIntrinsics, width: usize, height: usize) -> IppResult<(IppImageBuf<f32>, IppImageBuf<f32>)> {
+  ipp_build_remap(width, height, |x, y| {
+    let nx = (x as f64 - dst_camera.cx) / dst_camera.fx;
+    let ny = (y as f64 - dst_camera.cy) / dst_camera.fy;
+    let (dx, dy) = distortion.distort(nx, ny);
+    ((src_camera.fx * dx + src_camera.cx) as f32, (src_camera.fy * dy + src_camera.cy) as f32)
+  })
+}
+
+/// Samples a src image at the coordinates given by a pair of single channel
+/// f32 maps, one dst pixel per map pixel. Dst pixels whose coordinates fall
+/// outside the src image are left untouched.
+pub struct IppRemap<T> where T: Copy {
+  kind:     IppRemapKind,
+  map_x:    IppImageBuf<f32>,
+  map_y:    IppImageBuf<f32>,
+  _mrk:     PhantomData<fn (T)>,
+}
+
+impl<T> IppRemap<T> where T: IppWarpPixel {
+  pub fn new(kind: IppRemapKind, map_x: IppImageBuf<f32>, map_y: IppImageBuf<f32>) -> IppRemap<T> {
+    IppRemap::try_new(kind, map_x, map_y).unwrap()
+  }
+
+  pub fn try_new(kind: IppRemapKind, map_x: IppImageBuf<f32>, map_y: IppImageBuf<f32>) -> IppResult<IppRemap<T>> {
+    if map_x.layout() != IppChannelLayout::C1 || map_y.layout() != IppChannelLayout::C1 {
+      return Err(IppError::InvalidArg("remap: maps must have a single channel"));
+    }
+    if !(map_x.width() == map_y.width() && map_x.height() == map_y.height()) {
+      return Err(IppError::InvalidArg("remap: x and y maps differ in size"));
+    }
+    Ok(IppRemap{
+      kind:     kind,
+      map_x:    map_x,
+      map_y:    map_y,
+      _mrk:     PhantomData,
+    })
+  }
+
+  pub fn kind(&self) -> IppRemapKind {
+    self.kind
+  }
+
+  pub fn dst_size(&self) -> (usize, usize) {
+    (self.map_x.width(), self.map_x.height())
+  }
+
+  pub fn maps(&self) -> (&IppImageBuf<f32>, &IppImageBuf<f32>) {
+    (&self.map_x, &self.map_y)
+  }
+
+  pub fn remap(&self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_remap(src, dst).unwrap();
+  }
+
+  pub fn try_remap(&self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    let (dst_width, dst_height) = self.dst_size();
+    if !(dst.width() == dst_width && dst.height() == dst_height) {
+      return Err(IppError::InvalidArg("remap: dst size does not match the map size"));
+    }
+    if src.layout() != dst.layout() {
+      return Err(IppError::InvalidArg("remap: src and dst layouts differ"));
+    }
+    let src_size = ipp_size(src.width(), src.height())?;
+    let dst_size = ipp_size(dst_width, dst_height)?;
+    let map_x = self.map_x.view();
+    let map_y = self.map_y.view();
+    let status = unsafe { T::ipp_remap(
+        src.layout(),
+        src.as_ptr(),
+        src_size,
+        src.pitch() as _,
+        IppiRect{x: 0, y: 0, width: src_size.width, height: src_size.height},
+        map_x.as_ptr(),
+        map_x.pitch() as _,
+        map_y.as_ptr(),
+        map_y.pitch() as _,
+        dst.as_mut_ptr(),
+        dst.pitch() as _,
+        dst_size,
+        self.kind.interpolation(),
+    ) };
+    ipp_check(status)?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_close(a: (f64, f64), b: (f64, f64)) {
+    assert!((a.0 - b.0).abs() < 1.0e-12 && (a.1 - b.1).abs() < 1.0e-12, "{:?} != {:?}", a, b);
+  }
+
+  #[test]
+  fn distort_without_coefficients_is_identity() {
+    let dist = IppRadialTangential::default();
+    assert_close(dist.distort(0.3, -0.7), (0.3, -0.7));
+  }
+
+  #[test]
+  fn distort_radial() {
+    let dist = IppRadialTangential{k1: 0.1, k2: 0.01, k3: 0.001, .. IppRadialTangential::default()};
+    // r^2 = 0.25, so the radial factor is 1 + 0.025 + 0.000625 + 0.000015625.
+    assert_close(dist.distort(0.5, 0.0), (0.5 * 1.025640625, 0.0));
+    assert_close(dist.distort(0.0, 0.0), (0.0, 0.0));
+  }
+
+  #[test]
+  fn distort_tangential() {
+    let dist = IppRadialTangential{p1: 0.01, p2: 0.02, .. IppRadialTangential::default()};
+    assert_close(dist.distort(0.5, 0.5), (0.525, 0.52));
+  }
+
+  #[test]
+  fn build_remap_rejects_overflowing_size() {
+    let maps = ipp_build_remap(usize::MAX, 2, |_, _| unreachable!());
+    assert_eq!(maps.err(), Some(IppError::InvalidSize{width: usize::MAX, height: 2}));
+  }
+
+  #[test]
+  fn undistort_maps_without_distortion_are_identity() {
+    let camera = IppCameraIntrinsics{fx: 500.0, fy: 480.0, cx: 3.5, cy: 2.0};
+    let (width, height) = (7, 5);
+    let (map_x, map_y) = ipp_undistort_maps(camera, IppRadialTangential::default(), camera, width, height).unwrap();
+    let mut xs = vec![0.0; width * height];
+    let mut ys = vec![0.0; width * height];
+    map_x.read(&mut xs);
+    map_y.read(&mut ys);
+    for y in 0 .. height {
+      for x in 0 .. width {
+        let i = y * width + x;
+        assert!((xs[i] - x as f32).abs() < 1.0e-4 && (ys[i] - y as f32).abs() < 1.0e-4, "({}, {}) -> ({}, {})", x, y, xs[i], ys[i]);
+      }
+    }
+  }
+}
@@ -0,0 +1,396 @@
+use {IppChannelLayout, IppError, IppImageBuf, IppPixel, IppResult, ipp_check, ipp_size};
+
+use libc::{c_int};
+
+use std::marker::{PhantomData};
+use std::mem::{size_of};
+
+/// A borrowed, read-only rectangle of pixels with an explicit pitch (in bytes).
+#[derive(Clone, Copy)]
+pub struct IppImageView<'a, T> where T: 'a + Copy {
+  ptr:      *const T,
+  width:    usize,
+  height:   usize,
+  pitch:    usize,
+  layout:   IppChannelLayout,
+  _mrk:     PhantomData<&'a T>,
+}
+
+/// A borrowed, mutable rectangle of pixels with an explicit pitch (in bytes).
+pub struct IppImageViewMut<'a, T> where T: 'a + Copy {
+  ptr:      *mut T,
+  width:    usize,
+  height:   usize,
+  pitch:    usize,
+  layout:   IppChannelLayout,
+  _mrk:     PhantomData<&'a mut T>,
+}
+
+// Views behave like `&[T]` and `&mut [T]` respectively.
+unsafe impl<'a, T> Send for IppImageView<'a, T> where T: 'a + Copy + Sync {}
+unsafe impl<'a, T> Sync for IppImageView<'a, T> where T: 'a + Copy + Sync {}
+unsafe impl<'a, T> Send for IppImageViewMut<'a, T> where T: 'a + Copy + Send {}
+unsafe impl<'a, T> Sync for IppImageViewMut<'a, T> where T: 'a + Copy + Sync {}
+
+// Byte offset of pixel (x, y) within a pitched image.
+fn roi_offset<T>(x: usize, y: usize, pitch: usize, layout: IppChannelLayout) -> usize {
+  y * pitch + x * layout.num_channels() * size_of::<T>()
+}
+
+// Validates that a host slice of `len` elements can hold a `width` x `height`
+// image whose rows start every `pitch` bytes.
+fn check_slice<T>(len: usize, width: usize, height: usize, pitch: usize, layout: IppChannelLayout) -> IppResult<()> {
+  let row_bytes = match width.checked_mul(layout.num_channels() * size_of::<T>()) {
+    Some(row_bytes) => row_bytes,
+    None => return Err(IppError::InvalidSize{width: width, height: height}),
+  };
+  if pitch < row_bytes {
+    return Err(IppError::InvalidArg("image view: pitch is smaller than a row"));
+  }
+  if !pitch.is_multiple_of(size_of::<T>()) {
+    return Err(IppError::InvalidArg("image view: pitch is not a multiple of the pixel depth"));
+  }
+  if pitch > c_int::MAX as usize {
+    return Err(IppError::InvalidArg("image view: pitch overflows c_int"));
+  }
+  if height == 0 {
+    return Ok(());
+  }
+  let end_bytes = match (height - 1).checked_mul(pitch).and_then(|off| off.checked_add(row_bytes)) {
+    Some(end_bytes) => end_bytes,
+    None => return Err(IppError::InvalidSize{width: width, height: height}),
+  };
+  let expected = end_bytes.div_ceil(size_of::<T>());
+  if len < expected {
+    return Err(IppError::BufferLen{expected: expected, actual: len});
+  }
+  Ok(())
+}
+
+fn check_roi(x: usize, y: usize, w: usize, h: usize, width: usize, height: usize) -> IppResult<()> {
+  match (x.checked_add(w), y.checked_add(h)) {
+    (Some(x_end), Some(y_end)) if x_end <= width && y_end <= height => Ok(()),
+    _ => Err(IppError::OutOfBounds),
+  }
+}
+
+impl<'a, T> IppImageView<'a, T> where T: 'a + Copy {
+  /// Borrows an externally owned image without copying. `pitch` is the
+  /// distance in bytes between the starts of consecutive rows.
+  pub fn from_slice(buf: &'a [T], width: usize, height: usize, pitch: usize, layout: IppChannelLayout) -> IppResult<IppImageView<'a, T>> {
+    check_slice::<T>(buf.len(), width, height, pitch, layout)?;
+    Ok(IppImageView{
+      ptr:      buf.as_ptr(),
+      width:    width,
+      height:   height,
+      pitch:    pitch,
+      layout:   layout,
+      _mrk:     PhantomData,
+    })
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn pitch(&self) -> usize {
+    self.pitch
+  }
+
+  pub fn layout(&self) -> IppChannelLayout {
+    self.layout
+  }
+
+  pub fn as_ptr(&self) -> *const T {
+    self.ptr
+  }
+
+  pub fn roi(&self, x: usize, y: usize, w: usize, h: usize) -> IppResult<IppImageView<'a, T>> {
+    check_roi(x, y, w, h, self.width, self.height)?;
+    let offset = roi_offset::<T>(x, y, self.pitch, self.layout);
+    Ok(IppImageView{
+      ptr:      unsafe { (self.ptr as *const u8).add(offset) as *const T },
+      width:    w,
+      height:   h,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    })
+  }
+}
+
+impl<'a, T> IppImageViewMut<'a, T> where T: 'a + Copy {
+  /// Mutably borrows an externally owned image without copying. `pitch` is
+  /// the distance in bytes between the starts of consecutive rows.
+  pub fn from_slice_mut(buf: &'a mut [T], width: usize, height: usize, pitch: usize, layout: IppChannelLayout) -> IppResult<IppImageViewMut<'a, T>> {
+    check_slice::<T>(buf.len(), width, height, pitch, layout)?;
+    Ok(IppImageViewMut{
+      ptr:      buf.as_mut_ptr(),
+      width:    width,
+      height:   height,
+      pitch:    pitch,
+      layout:   layout,
+      _mrk:     PhantomData,
+    })
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn pitch(&self) -> usize {
+    self.pitch
+  }
+
+  pub fn layout(&self) -> IppChannelLayout {
+    self.layout
+  }
+
+  pub fn as_ptr(&self) -> *const T {
+    self.ptr
+  }
+
+  pub fn as_mut_ptr(&mut self) -> *mut T {
+    self.ptr
+  }
+
+  pub fn as_view(&self) -> IppImageView<'_, T> {
+    IppImageView{
+      ptr:      self.ptr,
+      width:    self.width,
+      height:   self.height,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    }
+  }
+
+  pub fn roi(&self, x: usize, y: usize, w: usize, h: usize) -> IppResult<IppImageView<'_, T>> {
+    self.as_view().roi(x, y, w, h)
+  }
+
+  pub fn roi_mut(&mut self, x: usize, y: usize, w: usize, h: usize) -> IppResult<IppImageViewMut<'_, T>> {
+    check_roi(x, y, w, h, self.width, self.height)?;
+    let offset = roi_offset::<T>(x, y, self.pitch, self.layout);
+    Ok(IppImageViewMut{
+      ptr:      unsafe { (self.ptr as *mut u8).add(offset) as *mut T },
+      width:    w,
+      height:   h,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    })
+  }
+
+  /// Splits the view into the rows above `row` and the rows from `row` on.
+  pub fn split_at_row_mut(self, row: usize) -> IppResult<(IppImageViewMut<'a, T>, IppImageViewMut<'a, T>)> {
+    if row > self.height {
+      return Err(IppError::OutOfBounds);
+    }
+    let offset = roi_offset::<T>(0, row, self.pitch, self.layout);
+    let top = IppImageViewMut{
+      ptr:      self.ptr,
+      width:    self.width,
+      height:   row,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    };
+    let bottom = IppImageViewMut{
+      ptr:      unsafe { (self.ptr as *mut u8).add(offset) as *mut T },
+      width:    self.width,
+      height:   self.height - row,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    };
+    Ok((top, bottom))
+  }
+}
+
+impl<T> IppImageBuf<T> where T: Copy {
+  pub fn view(&self) -> IppImageView<'_, T> {
+    IppImageView{
+      ptr:      self.ptr,
+      width:    self.width,
+      height:   self.height,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    }
+  }
+
+  pub fn view_mut(&mut self) -> IppImageViewMut<'_, T> {
+    IppImageViewMut{
+      ptr:      self.ptr,
+      width:    self.width,
+      height:   self.height,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    }
+  }
+
+  pub fn roi(&self, x: usize, y: usize, w: usize, h: usize) -> IppResult<IppImageView<'_, T>> {
+    self.view().roi(x, y, w, h)
+  }
+
+  pub fn roi_mut(&mut self, x: usize, y: usize, w: usize, h: usize) -> IppResult<IppImageViewMut<'_, T>> {
+    check_roi(x, y, w, h, self.width, self.height)?;
+    let offset = roi_offset::<T>(x, y, self.pitch, self.layout);
+    Ok(IppImageViewMut{
+      ptr:      unsafe { (self.ptr as *mut u8).add(offset) as *mut T },
+      width:    w,
+      height:   h,
+      pitch:    self.pitch,
+      layout:   self.layout,
+      _mrk:     PhantomData,
+    })
+  }
+}
+
+pub fn ipp_copy_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) where T: IppPixel {
+  ipp_try_copy_image(src, dst).unwrap();
+}
+
+/// Copies `src` into `dst`; both views must have the same size and layout.
+pub fn ipp_try_copy_image<T>(src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> where T: IppPixel {
+  if src.layout != dst.layout {
+    return Err(IppError::InvalidArg("copy: src and dst layouts differ"));
+  }
+  if src.width != dst.width || src.height != dst.height {
+    return Err(IppError::InvalidArg("copy: src and dst sizes differ"));
+  }
+  let size = ipp_size(src.width, src.height)?;
+  let status = unsafe { T::ipp_copy(
+      src.layout,
+      src.ptr,
+      src.pitch as _,
+      dst.ptr,
+      dst.pitch as _,
+      size,
+  ) };
+  ipp_check(status)?;
+  Ok(())
+}
+
+pub fn ipp_fill_image<T>(dst: &mut IppImageViewMut<T>, value: [T; 4]) where T: IppPixel {
+  ipp_try_fill_image(dst, value).unwrap();
+}
+
+/// Sets every pixel of `dst` to `value`; only the first `num_channels` values
+/// are used, and AC4 images keep their alpha channel.
+pub fn ipp_try_fill_image<T>(dst: &mut IppImageViewMut<T>, value: [T; 4]) -> IppResult<()> where T: IppPixel {
+  let size = ipp_size(dst.width, dst.height)?;
+  let status = unsafe { T::ipp_set(
+      dst.layout,
+      value.as_ptr(),
+      dst.ptr,
+      dst.pitch as _,
+      size,
+  ) };
+  ipp_check(status)?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn check_slice_pitch() {
+    let c1 = IppChannelLayout::C1;
+    assert!(matches!(check_slice::<u8>(100, 4, 2, 3, c1), Err(IppError::InvalidArg(_))));
+    assert!(matches!(check_slice::<u16>(100, 2, 2, 5, c1), Err(IppError::InvalidArg(_))));
+    assert!(matches!(check_slice::<u8>(usize::MAX, 4, 2, c_int::MAX as usize + 1, c1), Err(IppError::InvalidArg(_))));
+    assert_eq!(check_slice::<u16>(100, 2, 2, 4, c1), Ok(()));
+  }
+
+  #[test]
+  fn check_slice_last_row() {
+    // Rows of 2 x 3 u16s spaced 16 bytes apart; the last row ends at byte
+    // 2 * 16 + 12 = 44, i.e. element 22.
+    let c3 = IppChannelLayout::C3;
+    assert_eq!(check_slice::<u16>(21, 2, 3, 16, c3), Err(IppError::BufferLen{expected: 22, actual: 21}));
+    assert_eq!(check_slice::<u16>(22, 2, 3, 16, c3), Ok(()));
+    assert_eq!(check_slice::<u16>(24, 2, 3, 16, c3), Ok(()));
+    assert_eq!(check_slice::<u8>(0, 4, 0, 4, IppChannelLayout::C1), Ok(()));
+  }
+
+  #[test]
+  fn check_slice_overflow() {
+    let width = usize::MAX / 8;
+    assert_eq!(check_slice::<f32>(usize::MAX, width, 1, 16, IppChannelLayout::C4), Err(IppError::InvalidSize{width: width, height: 1}));
+    let pitch = c_int::MAX as usize - 3;
+    assert_eq!(check_slice::<u8>(usize::MAX, 4, usize::MAX, pitch, IppChannelLayout::C1), Err(IppError::InvalidSize{width: 4, height: usize::MAX}));
+  }
+
+  #[test]
+  fn from_slice_rejects_short_slice() {
+    let buf = [0u8; 11];
+    assert!(IppImageView::from_slice(&buf, 4, 3, 4, IppChannelLayout::C1).is_err());
+    assert!(IppImageView::from_slice(&buf[.. 10], 4, 3, 4, IppChannelLayout::C1).is_err());
+    let view = IppImageView::from_slice(&buf, 3, 3, 4, IppChannelLayout::C1).unwrap();
+    assert_eq!((view.width(), view.height(), view.pitch()), (3, 3, 4));
+    assert_eq!(view.as_ptr(), buf.as_ptr());
+  }
+
+  #[test]
+  fn roi_rejects_overflow_and_edges() {
+    let buf = [0u8; 4 * 3];
+    let view = IppImageView::from_slice(&buf, 4, 3, 4, IppChannelLayout::C1).unwrap();
+    assert_eq!(view.roi(1, 0, usize::MAX, 1).err(), Some(IppError::OutOfBounds));
+    assert_eq!(view.roi(0, 1, 1, usize::MAX).err(), Some(IppError::OutOfBounds));
+    assert_eq!(view.roi(usize::MAX, 0, 1, 1).err(), Some(IppError::OutOfBounds));
+    assert_eq!(view.roi(1, 0, 4, 1).err(), Some(IppError::OutOfBounds));
+    assert_eq!(view.roi(0, 2, 1, 2).err(), Some(IppError::OutOfBounds));
+    let roi = view.roi(1, 2, 3, 1).unwrap();
+    assert_eq!((roi.width(), roi.height(), roi.pitch()), (3, 1, 4));
+    assert_eq!(roi.as_ptr(), buf[9 ..].as_ptr());
+  }
+
+  #[test]
+  fn roi_mut_offsets_by_pixel() {
+    let mut buf = [0u16; 8 * 2];
+    let base = buf.as_ptr();
+    let mut view = IppImageViewMut::from_slice_mut(&mut buf, 2, 2, 16, IppChannelLayout::C3).unwrap();
+    assert_eq!(view.roi_mut(1, 2, 1, 1).err(), Some(IppError::OutOfBounds));
+    assert_eq!(view.roi_mut(2, 0, usize::MAX, 1).err(), Some(IppError::OutOfBounds));
+    let mut roi = view.roi_mut(1, 1, 1, 1).unwrap();
+    assert_eq!(roi.as_mut_ptr() as *const u16, unsafe { base.add(8 + 3) });
+  }
+
+  #[test]
+  fn split_at_row_mut_bounds() {
+    let mut buf = [0u8; 4 * 3];
+    let base = buf.as_ptr();
+    {
+      let view = IppImageViewMut::from_slice_mut(&mut buf, 4, 3, 4, IppChannelLayout::C1).unwrap();
+      let (top, bottom) = view.split_at_row_mut(0).unwrap();
+      assert_eq!((top.height(), bottom.height()), (0, 3));
+      assert_eq!(bottom.as_ptr(), base);
+    }
+    {
+      let view = IppImageViewMut::from_slice_mut(&mut buf, 4, 3, 4, IppChannelLayout::C1).unwrap();
+      let (top, bottom) = view.split_at_row_mut(1).unwrap();
+      assert_eq!((top.height(), bottom.height()), (1, 2));
+      assert_eq!((top.as_ptr(), bottom.as_ptr()), (base, unsafe { base.add(4) }));
+    }
+    {
+      let view = IppImageViewMut::from_slice_mut(&mut buf, 4, 3, 4, IppChannelLayout::C1).unwrap();
+      let (top, bottom) = view.split_at_row_mut(3).unwrap();
+      assert_eq!((top.height(), bottom.height()), (3, 0));
+      assert_eq!(top.width(), 4);
+    }
+    let view = IppImageViewMut::from_slice_mut(&mut buf, 4, 3, 4, IppChannelLayout::C1).unwrap();
+    assert_eq!(view.split_at_row_mut(4).err(), Some(IppError::OutOfBounds));
+  }
+}
@@ -0,0 +1,668 @@
+use ffi::*;
+use {IppBuf, IppChannelLayout, IppError, IppPixel, IppResult, ipp_check, ipp_size};
+use view::{IppImageView, IppImageViewMut};
+
+use libc::{c_int};
+
+use std::marker::{PhantomData};
+use std::ptr::{null};
+
+/// Pixel depths supported by the warp wrappers.
+pub trait IppWarpPixel: IppPixel {
+  fn data_type() -> IppDataType;
+
+  /// # Safety
+  ///
+  /// `spec` must have been initialized by `ippiWarpAffine*Init` for `kind`,
+  /// this depth and `layout`, and `buf` must point to at least the size
+  /// `ippiWarpGetBufferSize` reports for it. `src` must be an image of the
+  /// spec's src size and `dst` one covering `dst_offset` + `dst_size`, both
+  /// of `layout` as described on `IppPixel`.
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn ipp_warp_affine(
+      kind: IppWarpKind, layout: IppChannelLayout,
+      src: *const Self, src_step: c_int, dst: *mut Self, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+
+  /// # Safety
+  ///
+  /// As for `ipp_warp_affine`, with a spec from `ippiWarpPerspective*Init`.
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn ipp_warp_perspective(
+      kind: IppWarpKind, layout: IppChannelLayout,
+      src: *const Self, src_step: c_int, dst: *mut Self, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus;
+
+  /// # Safety
+  ///
+  /// `src` must be an image of `src_size` containing `src_roi`, and `dst` one
+  /// of `dst_size`, both of `layout` as described on `IppPixel`. `x_map` and
+  /// `y_map` must be single channel images of `dst_size` with the given
+  /// steps.
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn ipp_remap(
+      layout: IppChannelLayout,
+      src: *const Self, src_size: IppiSize, src_step: c_int, src_roi: IppiRect,
+      x_map: *const f32, x_map_step: c_int, y_map: *const f32, y_map_step: c_int,
+      dst: *mut Self, dst_step: c_int, dst_size: IppiSize,
+      interpolation: c_int) -> IppStatus;
+}
+
+impl IppWarpPixel for u8 {
+  fn data_type() -> IppDataType {
+    IppDataType::ipp8u
+  }
+
+  unsafe fn ipp_warp_affine(
+      kind: IppWarpKind, layout: IppChannelLayout,
+      src: *const u8, src_step: c_int, dst: *mut u8, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppWarpKind::Nearest, IppChannelLayout::C1)    => ippiWarpAffineNearest_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C3)    => ippiWarpAffineNearest_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C4)    => ippiWarpAffineNearest_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C1)     => ippiWarpAffineLinear_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C3)     => ippiWarpAffineLinear_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C4)     => ippiWarpAffineLinear_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C1)  => ippiWarpAffineCubic_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C3)  => ippiWarpAffineCubic_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C4)  => ippiWarpAffineCubic_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_warp_perspective(
+      kind: IppWarpKind, layout: IppChannelLayout,
+      src: *const u8, src_step: c_int, dst: *mut u8, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppWarpKind::Nearest, IppChannelLayout::C1)    => ippiWarpPerspectiveNearest_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C3)    => ippiWarpPerspectiveNearest_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C4)    => ippiWarpPerspectiveNearest_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C1)     => ippiWarpPerspectiveLinear_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C3)     => ippiWarpPerspectiveLinear_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C4)     => ippiWarpPerspectiveLinear_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C1)  => ippiWarpPerspectiveCubic_8u_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C3)  => ippiWarpPerspectiveCubic_8u_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C4)  => ippiWarpPerspectiveCubic_8u_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_remap(
+      layout: IppChannelLayout,
+      src: *const u8, src_size: IppiSize, src_step: c_int, src_roi: IppiRect,
+      x_map: *const f32, x_map_step: c_int, y_map: *const f32, y_map_step: c_int,
+      dst: *mut u8, dst_step: c_int, dst_size: IppiSize,
+      interpolation: c_int) -> IppStatus
+  {
+    match layout {
+      IppChannelLayout::C1  => ippiRemap_8u_C1R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+      IppChannelLayout::C3  => ippiRemap_8u_C3R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+      IppChannelLayout::C4  => ippiRemap_8u_C4R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+      IppChannelLayout::AC4 => ippiRemap_8u_AC4R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+    }
+  }
+}
+
+impl IppWarpPixel for f32 {
+  fn data_type() -> IppDataType {
+    IppDataType::ipp32f
+  }
+
+  unsafe fn ipp_warp_affine(
+      kind: IppWarpKind, layout: IppChannelLayout,
+      src: *const f32, src_step: c_int, dst: *mut f32, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppWarpKind::Nearest, IppChannelLayout::C1)    => ippiWarpAffineNearest_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C3)    => ippiWarpAffineNearest_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C4)    => ippiWarpAffineNearest_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C1)     => ippiWarpAffineLinear_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C3)     => ippiWarpAffineLinear_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C4)     => ippiWarpAffineLinear_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C1)  => ippiWarpAffineCubic_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C3)  => ippiWarpAffineCubic_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C4)  => ippiWarpAffineCubic_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_warp_perspective(
+      kind: IppWarpKind, layout: IppChannelLayout,
+      src: *const f32, src_step: c_int, dst: *mut f32, dst_step: c_int,
+      dst_offset: IppiPoint, dst_size: IppiSize,
+      spec: *const IppiWarpSpec, buf: *mut u8) -> IppStatus
+  {
+    match (kind, layout) {
+      (IppWarpKind::Nearest, IppChannelLayout::C1)    => ippiWarpPerspectiveNearest_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C3)    => ippiWarpPerspectiveNearest_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Nearest, IppChannelLayout::C4)    => ippiWarpPerspectiveNearest_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C1)     => ippiWarpPerspectiveLinear_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C3)     => ippiWarpPerspectiveLinear_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Linear, IppChannelLayout::C4)     => ippiWarpPerspectiveLinear_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C1)  => ippiWarpPerspectiveCubic_32f_C1R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C3)  => ippiWarpPerspectiveCubic_32f_C3R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      (IppWarpKind::Cubic{..}, IppChannelLayout::C4)  => ippiWarpPerspectiveCubic_32f_C4R(src, src_step, dst, dst_step, dst_offset, dst_size, spec, buf),
+      _ => IppStatus::IppStsNotSupportedModeErr,
+    }
+  }
+
+  unsafe fn ipp_remap(
+      layout: IppChannelLayout,
+      src: *const f32, src_size: IppiSize, src_step: c_int, src_roi: IppiRect,
+      x_map: *const f32, x_map_step: c_int, y_map: *const f32, y_map_step: c_int,
+      dst: *mut f32, dst_step: c_int, dst_size: IppiSize,
+      interpolation: c_int) -> IppStatus
+  {
+    match layout {
+      IppChannelLayout::C1  => ippiRemap_32f_C1R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+      IppChannelLayout::C3  => ippiRemap_32f_C3R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+      IppChannelLayout::C4  => ippiRemap_32f_C4R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+      IppChannelLayout::AC4 => ippiRemap_32f_AC4R(src, src_size, src_step, src_roi, x_map, x_map_step, y_map, y_map_step, dst, dst_step, dst_size, interpolation),
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IppWarpKind {
+  Nearest,
+  Linear,
+  Cubic{b: f32, c: f32},
+}
+
+/// How a warp treats dst pixels which map outside the src image.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IppWarpBorder {
+  /// Replicate the edge pixels of the src image.
+  Repl,
+  /// Fill with a constant pixel; only the first `num_channels` values are
+  /// used.
+  Const([f64; 4]),
+  /// Leave those dst pixels untouched.
+  Transp,
+}
+
+impl IppWarpBorder {
+  fn border_type(&self) -> IppiBorderType {
+    match *self {
+      IppWarpBorder::Repl     => IppiBorderType::ippBorderRepl,
+      IppWarpBorder::Const(_) => IppiBorderType::ippBorderConst,
+      IppWarpBorder::Transp   => IppiBorderType::ippBorderTransp,
+    }
+  }
+
+  fn border_value(&self) -> *const f64 {
+    match *self {
+      IppWarpBorder::Const(ref val) => val.as_ptr(),
+      _ => null(),
+    }
+  }
+}
+
+fn interpolation_type(kind: IppWarpKind) -> IppiInterpolationType {
+  match kind {
+    IppWarpKind::Nearest    => IppiInterpolationType::ippNearest,
+    IppWarpKind::Linear     => IppiInterpolationType::ippLinear,
+    IppWarpKind::Cubic{..}  => IppiInterpolationType::ippCubic,
+  }
+}
+
+fn check_warp_layout(layout: IppChannelLayout) -> IppResult<()> {
+  match layout {
+    IppChannelLayout::AC4 => Err(IppError::Unsupported("warp: AC4 layout is not supported, use C4")),
+    _ => Ok(()),
+  }
+}
+
+fn check_warp_views<T>(layout: IppChannelLayout, src_size: (usize, usize), dst_size: (usize, usize), src: &IppImageView<T>, dst: &IppImageViewMut<T>) -> IppResult<()> where T: Copy {
+  if !(src_size.0 == src.width() && src_size.1 == src.height()) {
+    return Err(IppError::InvalidArg("warp: src size does not match the warp src size"));
+  }
+  if !(dst_size.0 <= dst.width() && dst_size.1 <= dst.height()) {
+    return Err(IppError::OutOfBounds);
+  }
+  if src.layout() != layout || dst.layout() != layout {
+    return Err(IppError::InvalidArg("warp: image layout does not match warp layout"));
+  }
+  Ok(())
+}
+
+/// The 2x3 matrix which rotates by `angle` degrees counter-clockwise (as
+/// displayed, with y pointing down) and scales by `scale` about the point
+/// (`center_x`, `center_y`).
+pub fn ipp_affine_rotation(center_x: f64, center_y: f64, angle: f64, scale: f64) -> [[f64; 3]; 2] {
+  let theta = angle.to_radians();
+  let a = scale * theta.cos();
+  let b = scale * theta.sin();
+  [
+    [a,  b, (1.0 - a) * center_x - b * center_y],
+    [-b, a, b * center_x + (1.0 - a) * center_y],
+  ]
+}
+
+/// An initialized affine warp which maps src pixel (x, y) to dst pixel
+/// `(c[0][0] x + c[0][1] y + c[0][2], c[1][0] x + c[1][1] y + c[1][2])`.
+pub struct IppWarpAffine<T> where T: Copy {
+  spec: IppBuf<u8>,
+  buf:  IppBuf<u8>,
+  kind: IppWarpKind,
+  lay:  IppChannelLayout,
+  src:  (usize, usize),
+  dst:  (usize, usize),
+  coeffs: [[f64; 3]; 2],
+  _mrk: PhantomData<fn (T)>,
+}
+
+impl<T> IppWarpAffine<T> where T: IppWarpPixel {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(kind: IppWarpKind, layout: IppChannelLayout, coeffs: [[f64; 3]; 2], border: IppWarpBorder, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppWarpAffine<T> {
+    IppWarpAffine::try_new(kind, layout, coeffs, border, src_width, src_height, dst_width, dst_height).unwrap()
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  pub fn try_new(kind: IppWarpKind, layout: IppChannelLayout, coeffs: [[f64; 3]; 2], border: IppWarpBorder, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<IppWarpAffine<T>> {
+    check_warp_layout(layout)?;
+    let src_size = ipp_size(src_width, src_height)?;
+    let dst_size = ipp_size(dst_width, dst_height)?;
+    let mut spec_size = 0;
+    let mut init_buf_size = 0;
+    let status = unsafe { ippiWarpAffineGetSize(
+        src_size,
+        dst_size,
+        T::data_type(),
+        coeffs.as_ptr(),
+        interpolation_type(kind),
+        IppiWarpDirection::ippWarpForward,
+        border.border_type(),
+        &mut spec_size as *mut _,
+        &mut init_buf_size as *mut _,
+    ) };
+    ipp_check(status)?;
+    let mut spec = IppBuf::<u8>::try_alloc(spec_size as _)?;
+    let status = match kind {
+      IppWarpKind::Nearest => unsafe { ippiWarpAffineNearestInit(
+          src_size,
+          dst_size,
+          T::data_type(),
+          coeffs.as_ptr(),
+          IppiWarpDirection::ippWarpForward,
+          layout.num_channels() as _,
+          border.border_type(),
+          border.border_value(),
+          0, // smooth edge.
+          spec.as_mut_ptr(),
+      ) },
+      IppWarpKind::Linear => unsafe { ippiWarpAffineLinearInit(
+          src_size,
+          dst_size,
+          T::data_type(),
+          coeffs.as_ptr(),
+          IppiWarpDirection::ippWarpForward,
+          layout.num_channels() as _,
+          border.border_type(),
+          border.border_value(),
+          0, // smooth edge.
+          spec.as_mut_ptr(),
+      ) },
+      IppWarpKind::Cubic{b, c} => {
+        let mut init_buf = IppBuf::<u8>::try_alloc(init_buf_size as _)?;
+        unsafe { ippiWarpAffineCubicInit(
+            src_size,
+            dst_size,
+            T::data_type(),
+            coeffs.as_ptr(),
+            IppiWarpDirection::ippWarpForward,
+            layout.num_channels() as _,
+            b as f64, c as f64,
+            border.border_type(),
+            border.border_value(),
+            0, // smooth edge.
+            spec.as_mut_ptr(),
+            init_buf.as_mut_ptr(),
+        ) }
+      }
+    };
+    ipp_check(status)?;
+    let mut buf_size = 0;
+    let status = unsafe { ippiWarpGetBufferSize(
+        spec.as_ptr(),
+        dst_size,
+        &mut buf_size as *mut _,
+    ) };
+    ipp_check(status)?;
+    let buf = IppBuf::<u8>::try_alloc(buf_size as _)?;
+    Ok(IppWarpAffine{
+      spec: spec,
+      buf:  buf,
+      kind: kind,
+      lay:  layout,
+      src:  (src_width, src_height),
+      dst:  (dst_width, dst_height),
+      coeffs: coeffs,
+      _mrk: PhantomData,
+    })
+  }
+
+  pub fn warp(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_warp(src, dst).unwrap();
+  }
+
+  pub fn try_warp(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    check_warp_views(self.lay, self.src, self.dst, src, dst)?;
+    let status = unsafe { T::ipp_warp_affine(
+        self.kind,
+        self.lay,
+        src.as_ptr(),
+        src.pitch() as _,
+        dst.as_mut_ptr(),
+        dst.pitch() as _,
+        IppiPoint{x: 0, y: 0},
+        IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
+        self.spec.as_ptr(),
+        self.buf.as_mut_ptr(),
+    ) };
+    ipp_check(status)?;
+    Ok(())
+  }
+
+  pub fn coeffs(&self) -> [[f64; 3]; 2] {
+    self.coeffs
+  }
+
+  pub fn src_size(&self) -> (usize, usize) {
+    self.src
+  }
+
+  pub fn dst_size(&self) -> (usize, usize) {
+    self.dst
+  }
+}
+
+// Whether three of the four points are collinear, up to a tolerance relative
+// to the extent of the quad.
+fn quad_is_degenerate(quad: &[(f64, f64); 4]) -> bool {
+  let (x0, y0) = quad[0];
+  let extent = quad.iter().fold(0.0_f64, |e, &(x, y)| e.max((x - x0).abs()).max((y - y0).abs()));
+  let tol = 1.0e-10 * extent * extent;
+  for skip in 0 .. 4 {
+    let mut tri = quad.iter().enumerate().filter(|&(k, _)| k != skip).map(|(_, &p)| p);
+    let (ax, ay) = tri.next().unwrap();
+    let (bx, by) = tri.next().unwrap();
+    let (cx, cy) = tri.next().unwrap();
+    let cross = (bx - ax) * (cy - ay) - (by - ay) * (cx - ax);
+    if cross.is_nan() || cross.abs() <= tol {
+      return true;
+    }
+  }
+  false
+}
+
+/// The 3x3 homography which maps each `src[i]` to `dst[i]`, normalized so
+/// that its bottom right coefficient is 1. Fails if three of the points in
+/// either quad are collinear.
+pub fn ipp_perspective_from_points(src: [(f64, f64); 4], dst: [(f64, f64); 4]) -> IppResult<[[f64; 3]; 3]> {
+  if quad_is_degenerate(&src) || quad_is_degenerate(&dst) {
+    return Err(IppError::InvalidArg("perspective: three of the points are collinear"));
+  }
+  // Each correspondence gives two rows of the 8x8 system in the unknowns
+  // h00, h01, h02, h10, h11, h12, h20, h21.
+  let mut a = [[0.0; 9]; 8];
+  for (i, (&(x, y), &(u, v))) in src.iter().zip(dst.iter()).enumerate() {
+    a[2 * i]     = [x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y, u];
+    a[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y, v];
+  }
+  for col in 0 .. 8 {
+    let mut pivot = col;
+    for row in col + 1 .. 8 {
+      if a[row][col].abs() > a[pivot][col].abs() {
+        pivot = row;
+      }
+    }
+    if a[pivot][col].is_nan() || a[pivot][col].abs() <= 1.0e-12 {
+      return Err(IppError::InvalidArg("perspective: degenerate point correspondences"));
+    }
+    a.swap(col, pivot);
+    let pivot_row = a[col];
+    for (row, a_row) in a.iter_mut().enumerate() {
+      if row == col {
+        continue;
+      }
+      let f = a_row[col] / pivot_row[col];
+      for (x, &p) in a_row[col ..].iter_mut().zip(pivot_row[col ..].iter()) {
+        *x -= f * p;
+      }
+    }
+  }
+  let mut h = [0.0; 8];
+  for (k, h_k) in h.iter_mut().enumerate() {
+    *h_k = a[k][8] / a[k][k];
+  }
+  Ok([
+    [h[0], h[1], h[2]],
+    [h[3], h[4], h[5]],
+    [h[6], h[7], 1.0],
+  ])
+}
+
+/// An initialized perspective warp which maps src pixel (x, y) to dst pixel
+/// `((c[0] . p) / (c[2] . p), (c[1] . p) / (c[2] . p))`, where `p = (x, y, 1)`.
+pub struct IppWarpPerspective<T> where T: Copy {
+  spec: IppBuf<u8>,
+  buf:  IppBuf<u8>,
+  kind: IppWarpKind,
+  lay:  IppChannelLayout,
+  src:  (usize, usize),
+  dst:  (usize, usize),
+  coeffs: [[f64; 3]; 3],
+  _mrk: PhantomData<fn (T)>,
+}
+
+impl<T> IppWarpPerspective<T> where T: IppWarpPixel {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(kind: IppWarpKind, layout: IppChannelLayout, coeffs: [[f64; 3]; 3], border: IppWarpBorder, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppWarpPerspective<T> {
+    IppWarpPerspective::try_new(kind, layout, coeffs, border, src_width, src_height, dst_width, dst_height).unwrap()
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  pub fn try_new(kind: IppWarpKind, layout: IppChannelLayout, coeffs: [[f64; 3]; 3], border: IppWarpBorder, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<IppWarpPerspective<T>> {
+    check_warp_layout(layout)?;
+    let src_size = ipp_size(src_width, src_height)?;
+    let dst_size = ipp_size(dst_width, dst_height)?;
+    let src_roi = IppiRect{x: 0, y: 0, width: src_size.width, height: src_size.height};
+    let mut spec_size = 0;
+    let mut init_buf_size = 0;
+    let status = unsafe { ippiWarpPerspectiveGetSize(
+        src_size,
+        src_roi,
+        dst_size,
+        T::data_type(),
+        coeffs.as_ptr(),
+        interpolation_type(kind),
+        IppiWarpDirection::ippWarpForward,
+        border.border_type(),
+        &mut spec_size as *mut _,
+        &mut init_buf_size as *mut _,
+    ) };
+    ipp_check(status)?;
+    let mut spec = IppBuf::<u8>::try_alloc(spec_size as _)?;
+    let status = match kind {
+      IppWarpKind::Nearest => unsafe { ippiWarpPerspectiveNearestInit(
+          src_size,
+          src_roi,
+          dst_size,
+          T::data_type(),
+          coeffs.as_ptr(),
+          IppiWarpDirection::ippWarpForward,
+          layout.num_channels() as _,
+          border.border_type(),
+          border.border_value(),
+          0, // smooth edge.
+          spec.as_mut_ptr(),
+      ) },
+      IppWarpKind::Linear => unsafe { ippiWarpPerspectiveLinearInit(
+          src_size,
+          src_roi,
+          dst_size,
+          T::data_type(),
+          coeffs.as_ptr(),
+          IppiWarpDirection::ippWarpForward,
+          layout.num_channels() as _,
+          border.border_type(),
+          border.border_value(),
+          0, // smooth edge.
+          spec.as_mut_ptr(),
+      ) },
+      IppWarpKind::Cubic{b, c} => {
+        let mut init_buf = IppBuf::<u8>::try_alloc(init_buf_size as _)?;
+        unsafe { ippiWarpPerspectiveCubicInit(
+            src_size,
+            src_roi,
+            dst_size,
+            T::data_type(),
+            coeffs.as_ptr(),
+            IppiWarpDirection::ippWarpForward,
+            layout.num_channels() as _,
+            b as f64, c as f64,
+            border.border_type(),
+            border.border_value(),
+            0, // smooth edge.
+            spec.as_mut_ptr(),
+            init_buf.as_mut_ptr(),
+        ) }
+      }
+    };
+    ipp_check(status)?;
+    let mut buf_size = 0;
+    let status = unsafe { ippiWarpGetBufferSize(
+        spec.as_ptr(),
+        dst_size,
+        &mut buf_size as *mut _,
+    ) };
+    ipp_check(status)?;
+    let buf = IppBuf::<u8>::try_alloc(buf_size as _)?;
+    Ok(IppWarpPerspective{
+      spec: spec,
+      buf:  buf,
+      kind: kind,
+      lay:  layout,
+      src:  (src_width, src_height),
+      dst:  (dst_width, dst_height),
+      coeffs: coeffs,
+      _mrk: PhantomData,
+    })
+  }
+
+  /// Creates the warp which maps the src quad `src_quad` onto the dst quad
+  /// `dst_quad`, e.g. the four corners of a photographed document onto the
+  /// corners of the dst image.
+  #[allow(clippy::too_many_arguments)]
+  pub fn try_from_points(kind: IppWarpKind, layout: IppChannelLayout, src_quad: [(f64, f64); 4], dst_quad: [(f64, f64); 4], border: IppWarpBorder, src_width: usize, src_height: usize, dst_width: usize, dst_height: usize) -> IppResult<IppWarpPerspective<T>> {
+    let coeffs = ipp_perspective_from_points(src_quad, dst_quad)?;
+    IppWarpPerspective::try_new(kind, layout, coeffs, border, src_width, src_height, dst_width, dst_height)
+  }
+
+  pub fn warp(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) {
+    self.try_warp(src, dst).unwrap();
+  }
+
+  pub fn try_warp(&mut self, src: &IppImageView<T>, dst: &mut IppImageViewMut<T>) -> IppResult<()> {
+    check_warp_views(self.lay, self.src, self.dst, src, dst)?;
+    let status = unsafe { T::ipp_warp_perspective(
+        self.kind,
+        self.lay,
+        src.as_ptr(),
+        src.pitch() as _,
+        dst.as_mut_ptr(),
+        dst.pitch() as _,
+        IppiPoint{x: 0, y: 0},
+        IppiSize{width: self.dst.0 as _, height: self.dst.1 as _},
+        self.spec.as_ptr(),
+        self.buf.as_mut_ptr(),
+    ) };
+    ipp_check(status)?;
+    Ok(())
+  }
+
+  pub fn coeffs(&self) -> [[f64; 3]; 3] {
+    self.coeffs
+  }
+
+  pub fn src_size(&self) -> (usize, usize) {
+    self.src
+  }
+
+  pub fn dst_size(&self) -> (usize, usize) {
+    self.dst
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn project(h: &[[f64; 3]; 3], x: f64, y: f64) -> (f64, f64) {
+    let w = h[2][0] * x + h[2][1] * y + h[2][2];
+    ((h[0][0] * x + h[0][1] * y + h[0][2]) / w, (h[1][0] * x + h[1][1] * y + h[1][2]) / w)
+  }
+
+  #[test]
+  fn perspective_from_points_round_trip() {
+    let h = [
+      [1.2,    0.1,   5.0],
+      [-0.05,  0.9,   3.0],
+      [0.001,  0.002, 1.0],
+    ];
+    let src = [(0.0, 0.0), (640.0, 0.0), (640.0, 480.0), (0.0, 480.0)];
+    let mut dst = [(0.0, 0.0); 4];
+    for (d, &(x, y)) in dst.iter_mut().zip(src.iter()) {
+      *d = project(&h, x, y);
+    }
+    let solved = ipp_perspective_from_points(src, dst).unwrap();
+    for (row, solved_row) in h.iter().zip(solved.iter()) {
+      for (&c, &solved_c) in row.iter().zip(solved_row.iter()) {
+        assert!((c - solved_c).abs() < 1.0e-9, "{:?} != {:?}", h, solved);
+      }
+    }
+  }
+
+  #[test]
+  fn perspective_from_points_identity() {
+    let quad = [(1.0, 2.0), (9.0, 2.0), (9.0, 7.0), (1.0, 7.0)];
+    let h = ipp_perspective_from_points(quad, quad).unwrap();
+    for (i, row) in h.iter().enumerate() {
+      for (j, &c) in row.iter().enumerate() {
+        let expected = if i == j { 1.0 } else { 0.0 };
+        assert!((c - expected).abs() < 1.0e-12);
+      }
+    }
+  }
+
+  #[test]
+  fn perspective_from_points_rejects_collinear() {
+    let collinear = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (0.0, 5.0)];
+    let quad = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+    assert!(ipp_perspective_from_points(collinear, quad).is_err());
+    assert!(ipp_perspective_from_points(quad, collinear).is_err());
+    assert!(ipp_perspective_from_points([(3.0, 4.0); 4], quad).is_err());
+  }
+
+  #[test]
+  fn affine_rotation_quarter_turn() {
+    let (cx, cy) = (4.0, 3.0);
+    let c = ipp_affine_rotation(cx, cy, 90.0, 1.0);
+    let (x, y) = (cx + 1.0, cy);
+    let mapped = (c[0][0] * x + c[0][1] * y + c[0][2], c[1][0] * x + c[1][1] * y + c[1][2]);
+    assert!((mapped.0 - cx).abs() < 1.0e-12 && (mapped.1 - (cy - 1.0)).abs() < 1.0e-12, "{:?}", mapped);
+    let center = (c[0][0] * cx + c[0][1] * cy + c[0][2], c[1][0] * cx + c[1][1] * cy + c[1][2]);
+    assert!((center.0 - cx).abs() < 1.0e-12 && (center.1 - cy).abs() < 1.0e-12, "{:?}", center);
+  }
+}